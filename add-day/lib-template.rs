@@ -0,0 +1,67 @@
+use utils::parse::ParseError;
+use utils::{Solver, split_lines};
+
+pub struct {name};
+
+impl Solver for {name} {
+    const EVENT: u16 = {event};
+    const DAY: u8 = {number};
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(split_lines(input))
+    }
+
+    fn part1(&self, input: &Vec<String>) -> usize {
+        part_1(input)
+    }
+
+    fn part2(&self, input: &Vec<String>) -> usize {
+        part_2(input)
+    }
+
+    fn part3(&self, input: &Vec<String>) -> usize {
+        part_3(input)
+    }
+}
+
+fn part_1(_input: &[String]) -> usize {
+    0
+}
+
+fn part_2(_input: &[String]) -> usize {
+    0
+}
+
+fn part_3(_input: &[String]) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(part: u8) -> String {
+        // The examples of the quest statement, stored next to the inputs
+        utils::read_input(utils::resolve(utils::example_path({name}::EVENT, {name}::DAY, part)))
+    }
+
+    #[test]
+    #[ignore = "add the example and its answer"]
+    fn part_1_example() {
+        assert_eq!({name}.part1(&{name}.parse(&example(1)).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "add the example and its answer"]
+    fn part_2_example() {
+        assert_eq!({name}.part2(&{name}.parse(&example(2)).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "add the example and its answer"]
+    fn part_3_example() {
+        assert_eq!({name}.part3(&{name}.parse(&example(3)).unwrap()), 0);
+    }
+}
@@ -1,17 +1,3 @@
-fn main() {
-    part_1();
-    part_2();
-    part_3();
-}
-
-fn part_1() {
-    println!("Part 1: {:?}", 0);
-}
-
-fn part_2() {
-    println!("Part 2: {:?}", 0);
-}
-
-fn part_3() {
-    println!("Part 3: {:?}", 0);
-}
\ No newline at end of file
+fn main() {
+    utils::run_quest(&{crate}::{name});
+}
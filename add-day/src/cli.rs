@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use utils::DAYS;
+use utils::args::{ArgsError, parse_number};
+
+pub const USAGE: &str = "\
+Usage: add-day [--day N] [--event YEAR] [--itertools] [--rayon] [--root PATH]
+
+Options:
+    --day N        Create day N instead of the first day that does not exist yet
+    --event YEAR   Create the quest for the event of YEAR in eventYYYY/questNN, instead of a day
+                   of the current event
+    --itertools    Add itertools as dependency of the new day
+    --rayon        Add rayon as dependency of the new day
+    --root PATH    Create the day in the workspace at PATH, instead of $EVERYBODY_CODES_ROOT or the
+                   workspace add-day was built in
+    --help         Show this message";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub day: Option<u8>,
+    pub event: Option<u16>,
+    pub itertools: bool,
+    pub rayon: bool,
+    pub root: Option<PathBuf>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, ArgsError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => parsed.day = Some(parse_number(&arg, args.next(), DAYS)?),
+                "--event" => parsed.event = Some(parse_number(&arg, args.next(), 2024..=9999)?),
+                "--itertools" => parsed.itertools = true,
+                "--rayon" => parsed.rayon = true,
+                "--root" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.root = Some(PathBuf::from(path));
+                }
+                "--help" | "-h" => return Err(ArgsError::Help),
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        assert_eq!(parse(&["--day", "7", "--event", "2024", "--rayon", "--root", "codes"]), Ok(Args {
+            day: Some(7),
+            event: Some(2024),
+            rayon: true,
+            root: Some(PathBuf::from("codes")),
+            ..Args::default()
+        }));
+        assert_eq!(parse(&["-h"]), Err(ArgsError::Help));
+        assert_eq!(parse(&["--days"]), Err(ArgsError::Unknown(String::from("--days"))));
+    }
+
+    #[test]
+    fn day_range() {
+        assert_eq!(parse(&["--day", "1"]).unwrap().day, Some(1));
+        assert_eq!(parse(&["--day", "20"]).unwrap().day, Some(20));
+        for day in ["0", "21", "-1", "x"] {
+            assert_eq!(parse(&["--day", day]), Err(ArgsError::InvalidValue(String::from("--day"), day.to_string())));
+        }
+        assert_eq!(parse(&["--day"]), Err(ArgsError::MissingValue(String::from("--day"))));
+    }
+
+    #[test]
+    fn event_range() {
+        assert_eq!(parse(&["--event", "9999"]).unwrap().event, Some(9999));
+        for event in ["2023", "10000", "24"] {
+            assert_eq!(parse(&["--event", event]), Err(ArgsError::InvalidValue(String::from("--event"), event.to_string())));
+        }
+        assert_eq!(parse(&["--event"]), Err(ArgsError::MissingValue(String::from("--event"))));
+    }
+}
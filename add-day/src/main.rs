@@ -1,104 +1,228 @@
-use std::env::current_dir;
+use std::collections::BTreeSet;
+use std::env;
 use std::fs;
 use std::io::{Write, Result};
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, ExitCode};
 use regex::Regex;
 
-fn main() {
-    let cwd = current_dir().expect("Failed to read current directory");
-    let new_day = get_new_day_number(&cwd).expect("Failed to get new day");
-    println!("Creating new day: {}", new_day);
-    let new_day_path = cwd.join(new_day.to_string());
+use utils::args::ArgsError;
+use utils::{CURRENT_EVENT, DAYS, root, set_root};
+
+mod cli;
+mod workspace;
+
+use cli::{Args, USAGE};
+
+// Optional dependencies of a new day, enabled by a flag of the same name
+const ITERTOOLS: &str = "itertools = \"0.14.0\"";
+const RAYON: &str = "rayon = \"1.11.0\"";
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(root) = &args.root {
+        set_root(root);
+    }
+    let workspace_root = root();
+    // Days of the current event live in the root of the workspace as dayNN, the quests of other
+    // events in their own eventYYYY folder as questNN
+    let event_folder = match args.event {
+        Some(event) if event != CURRENT_EVENT => Some(format!("event{}", event)),
+        _ => None,
+    };
+    let event_dir = event_folder.as_ref().map_or(workspace_root.clone(), |folder| workspace_root.join(folder));
+    let prefix = if event_folder.is_some() { "quest" } else { "day" };
+
+    let number = match args.day {
+        Some(number) => number,
+        None => match get_new_day_number(&event_dir, prefix).expect("Failed to get new day") {
+            Some(number) => number,
+            None => {
+                eprintln!("error: every day of the event already exists");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let day = format!("{}{:02}", prefix, number);
+    let new_day_path = event_dir.join(&day);
+    if new_day_path.exists() {
+        eprintln!("error: {} already exists", new_day_path.display());
+        return ExitCode::FAILURE;
+    }
+
+    // Package names have to be unique within the workspace, so days of other events are prefixed
+    let (member, crate_name) = match &event_folder {
+        Some(folder) => (format!("{}/{}", folder, day), format!("{}-{}", folder, day)),
+        None => (day.clone(), day.clone()),
+    };
+
+    println!("Creating new day: {}", member);
+    fs::create_dir_all(&event_dir).expect("Failed to create event folder");
+    if !create_new_day(&new_day_path, &crate_name).expect("Failed to create new day") {
+        return ExitCode::FAILURE;
+    }
     println!("Setting up files for the project");
-    let _ = create_new_day(&new_day).expect("Failed to create new day");
-    let _ = setup_files(&new_day_path).expect("Failed to setup files");
-    let _ = add_input_files(&new_day);
+    let mut dependencies = vec![];
+    if args.itertools {
+        dependencies.push(ITERTOOLS);
+    }
+    if args.rayon {
+        dependencies.push(RAYON);
+    }
+    let event = args.event.unwrap_or(CURRENT_EVENT);
+    setup_files(&new_day_path, &crate_name, event, number, &workspace_root, &dependencies).expect("Failed to setup files");
+    workspace::register_member(&workspace_root, &member).expect("Failed to register the day in the workspace");
+    add_input_files(&event_dir.join("inputs"), &day).expect("Failed to add input files");
+    ExitCode::SUCCESS
 }
 
-fn create_new_day(day: &str) -> Result<()> {
+fn create_new_day(path: &Path, crate_name: &str) -> Result<bool> {
+    // Returns whether cargo succeeded in creating the crate
     let output = Command::new("cargo")
-        .args(["new", &day])
-        .output()
-        .expect("Failed to create new day");
+        .arg("new")
+        .arg(path)
+        .args(["--name", crate_name])
+        .output()?;
 
     if !output.status.success() {
         eprintln!("Cargo command failed with status: {}", output.status);
         eprintln!("stderr: {}", String::from_utf8_lossy(&output.stderr));
     }
 
-    Ok(())
+    Ok(output.status.success())
 }
 
-fn get_new_day_number(dir: &PathBuf) -> Result<String> {
-    let mut day_vec = Vec::new();
-
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+fn get_new_day_number(dir: &Path, prefix: &str) -> Result<Option<u8>> {
+    // The first day that does not exist yet, so gaps are filled before new days are appended
+    let mut days = BTreeSet::new();
 
-        if path.is_dir() && is_day_folder(&path) {
-            day_vec.push(path.file_name().unwrap().to_owned());
+    if dir.is_dir() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() && let Some(number) = day_folder_number(&path, prefix) {
+                days.insert(number);
+            }
         }
     }
 
-    if day_vec.is_empty() {
-        return Ok(String::from("day01"));
-    }
-
-    day_vec.sort();
-    let last_day = day_vec[day_vec.len() - 1]
-        .to_str()
-        .expect("Could not parse str from OsString")
-        .trim_start_matches("day")
-        .parse::<usize>()
-        .expect("Could not parse day number");
-    let new_day = format!("day{:02}", last_day + 1);
-
-    Ok(new_day)
+    Ok(DAYS.into_iter().find(|number| !days.contains(number)))
 }
 
-fn is_day_folder(path: &PathBuf) -> bool {
-    let re = Regex::new(r"day\d\d").unwrap();
-    let folder_name = path.file_name().expect("Could not get filename").to_string_lossy();
-    re.is_match(&folder_name)
+fn day_folder_number(path: &Path, prefix: &str) -> Option<u8> {
+    let re = Regex::new(&format!(r"^{}(\d{{2}})$", prefix)).unwrap();
+    let folder_name = path.file_name()?.to_string_lossy();
+    re.captures(&folder_name)
+        .and_then(|captures| captures[1].parse::<u8>().ok())
 }
 
-fn setup_files(dir: &PathBuf) -> Result<()> {
-    let main_path = dir.clone().join("src").join("main.rs");
+fn setup_files(dir: &Path, crate_name: &str, event: u16, number: u8, root: &Path, dependencies: &[&str]) -> Result<()> {
+    let src = dir.join("src");
     let toml_path = dir.join("Cargo.toml");
-    setup_main(&main_path)?;
-    setup_cargo_toml(&toml_path)?;
+    let placeholders = [
+        ("{crate}", crate_name.replace('-', "_")),
+        ("{name}", format!("Day{:02}", number)),
+        ("{event}", event.to_string()),
+        ("{number}", number.to_string()),
+    ];
+    setup_template("main-template.rs", &src.join("main.rs"), &placeholders, root)?;
+    setup_template("lib-template.rs", &src.join("lib.rs"), &placeholders, root)?;
+
+    let utils_path = if dir.parent() == Some(root) { "../utils" } else { "../../utils" };
+    setup_cargo_toml(&toml_path, utils_path, dependencies)?;
     Ok(())
 }
 
-fn setup_main(path: &PathBuf) -> Result<()> {
-    let cwd = current_dir()?;
-    let new_main = cwd.join("add-day").join("main-template.rs");
+fn setup_template(template: &str, path: &Path, placeholders: &[(&str, String)], root: &Path) -> Result<()> {
+    // The templates refer to the crate as {crate}, to the quest struct as {name}, to the event as
+    // {event} and to the day as {number}, e.g. event2024_quest07, Day07, 2024 and 7
+    let template = fs::read_to_string(root.join("add-day").join(template))?;
 
-    fs::copy(new_main, path)?;
+    let contents = placeholders.iter()
+        .fold(template, |contents, (placeholder, value)| contents.replace(placeholder, value));
+    fs::write(path, contents)?;
     Ok(())
 }
 
-fn setup_cargo_toml(path: &PathBuf) -> Result<()> {
+fn setup_cargo_toml(path: &Path, utils_path: &str, dependencies: &[&str]) -> Result<()> {
+    let contents = fs::read_to_string(path)?;
     let mut file = fs::OpenOptions::new()
         .append(true)
         .open(path)?;
 
-    write!(file, "{}", "utils = { path = \"../utils\" }")?;
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        writeln!(file)?;
+    }
+    writeln!(file, "utils = {{ path = \"{}\" }}", utils_path)?;
+    for dependency in dependencies {
+        writeln!(file, "{}", dependency)?;
+    }
     Ok(())
 }
 
-fn add_input_files(day: &str) -> Result<()> {
-    if !fs::exists("inputs")? {
-        fs::create_dir("inputs")?;
-    }
+fn add_input_files(dir: &Path, day: &str) -> Result<()> {
+    fs::create_dir_all(dir)?;
 
+    // Inputs and examples that were already downloaded are kept
     for i in 0..3 {
-        let filename = format!("{day}pt{}.txt", i + 1);
-        fs::File::create(format!("inputs/{filename}"))?;
+        for extension in ["txt", "example.txt"] {
+            let path = dir.join(format!("{day}pt{}.{extension}", i + 1));
+            if !path.exists() {
+                fs::File::create(path)?;
+            }
+        }
     }
 
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_folders_have_two_digits() {
+        assert_eq!(day_folder_number(Path::new("codes/day07"), "day"), Some(7));
+        assert_eq!(day_folder_number(Path::new("event2024/quest25"), "quest"), Some(25));
+        assert_eq!(day_folder_number(Path::new("day7"), "day"), None);
+        assert_eq!(day_folder_number(Path::new("day001"), "day"), None);
+        assert_eq!(day_folder_number(Path::new("day1x"), "day"), None);
+        assert_eq!(day_folder_number(Path::new("quest07"), "day"), None);
+    }
+
+    #[test]
+    fn gaps_are_filled_first() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(get_new_day_number(&dir.path().join("missing"), "day").unwrap(), Some(1));
+        for folder in ["day01", "day02", "day04", "day001", "day1x"] {
+            fs::create_dir(dir.path().join(folder)).unwrap();
+        }
+        // Files are not days
+        fs::write(dir.path().join("day03"), "").unwrap();
+        assert_eq!(get_new_day_number(dir.path(), "day").unwrap(), Some(3));
+
+        fs::remove_file(dir.path().join("day03")).unwrap();
+        fs::create_dir(dir.path().join("day03")).unwrap();
+        assert_eq!(get_new_day_number(dir.path(), "day").unwrap(), Some(5));
+    }
+
+    #[test]
+    fn no_day_after_the_last_one() {
+        let dir = tempfile::tempdir().unwrap();
+        for number in DAYS {
+            fs::create_dir(dir.path().join(format!("quest{:02}", number))).unwrap();
+        }
+        assert_eq!(get_new_day_number(dir.path(), "quest").unwrap(), None);
+        assert_eq!(get_new_day_number(dir.path(), "day").unwrap(), Some(1));
+    }
+}
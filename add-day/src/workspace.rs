@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+pub fn register_member(root: &Path, member: &str) -> Result<()> {
+    // Only the members are rewritten, the rest of the manifest keeps its comments and layout
+    let path = root.join("Cargo.toml");
+    let mut manifest: DocumentMut = fs::read_to_string(&path)?
+        .parse()
+        .map_err(|e| Error::other(format!("Could not parse {}: {}", path.display(), e)))?;
+
+    let workspace = manifest.entry("workspace")
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| Error::other("The workspace entry of the manifest is not a table"))?;
+    let current = match workspace.get("members") {
+        Some(item) => item.as_array()
+            .ok_or_else(|| Error::other("The workspace members should be an array"))?
+            .iter()
+            .map(|member| member.as_str()
+                .map(String::from)
+                .ok_or_else(|| Error::other("The workspace members should be strings")))
+            .collect::<Result<Vec<String>>>()?,
+        None => vec![],
+    };
+
+    let mut members = normalize_members(root, &current)?;
+    members.insert(member.to_string());
+
+    // One member per line, as cargo new would list them
+    let mut array = Array::new();
+    for member in members {
+        array.push_formatted(Value::from(member).decorated("\n    ", ""));
+    }
+    array.set_trailing("\n");
+    array.set_trailing_comma(true);
+    workspace.insert("members", Item::Value(Value::Array(array)));
+
+    fs::write(path, manifest.to_string())
+}
+
+fn normalize_members(root: &Path, members: &[String]) -> Result<BTreeSet<String>> {
+    // Every member is listed explicitly once, so glob patterns are replaced by the crates they
+    // match, and a trailing slash does not lead to a duplicate
+    let mut normalized = BTreeSet::new();
+    for member in members {
+        let member = member.trim_end_matches('/');
+        match expand(root, member)? {
+            Some(expanded) => normalized.extend(expanded),
+            None => {
+                normalized.insert(member.to_string());
+            }
+        }
+    }
+    Ok(normalized)
+}
+
+fn expand(root: &Path, member: &str) -> Result<Option<Vec<String>>> {
+    // Only a single * in the last component is expanded, e.g. event2024/quest*. Other patterns,
+    // and those that match nothing, are kept as they are for cargo to expand.
+    let (dir, name) = member.rsplit_once('/').unwrap_or(("", member));
+    if dir.contains(['*', '?', '[']) || name.contains(['?', '[']) {
+        return Ok(None);
+    }
+    let Some((prefix, suffix)) = name.split_once('*') else {
+        return Ok(None);
+    };
+    if suffix.contains('*') {
+        return Ok(None);
+    }
+
+    let entries = match fs::read_dir(root.join(dir)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut expanded = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        let name = path.file_name().expect("Could not get filename").to_string_lossy();
+        let matches = name.len() >= prefix.len() + suffix.len() && name.starts_with(prefix) && name.ends_with(suffix);
+        if matches && path.join("Cargo.toml").is_file() {
+            expanded.push(if dir.is_empty() { name.to_string() } else { format!("{}/{}", dir, name) });
+        }
+    }
+    Ok((!expanded.is_empty()).then_some(expanded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_crate(root: &Path, path: &str) {
+        fs::create_dir_all(root.join(path)).unwrap();
+        fs::write(root.join(path).join("Cargo.toml"), "[package]\n").unwrap();
+    }
+
+    fn members(root: &Path, members: &[&str]) -> Vec<String> {
+        let members: Vec<String> = members.iter().map(|member| member.to_string()).collect();
+        normalize_members(root, &members).unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn duplicates_and_trailing_slashes() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(members(root.path(), &["utils/", "day01", "utils", "day01/"]), vec!["day01", "utils"]);
+    }
+
+    #[test]
+    fn globs_are_expanded_to_crates() {
+        let root = tempfile::tempdir().unwrap();
+        add_crate(root.path(), "day02");
+        add_crate(root.path(), "day01");
+        // Not a crate
+        fs::create_dir(root.path().join("day03")).unwrap();
+        add_crate(root.path(), "event2024/quest01");
+        add_crate(root.path(), "event2024/quest10");
+
+        assert_eq!(members(root.path(), &["day*", "utils"]), vec!["day01", "day02", "utils"]);
+        assert_eq!(members(root.path(), &["event2024/quest*/"]), vec!["event2024/quest01", "event2024/quest10"]);
+    }
+
+    #[test]
+    fn unexpandable_globs_are_kept() {
+        let root = tempfile::tempdir().unwrap();
+        add_crate(root.path(), "event2024/quest01");
+        assert_eq!(
+            members(root.path(), &["event2023/quest*", "*/quest01", "day0?", "nothing*"]),
+            vec!["*/quest01", "day0?", "event2023/quest*", "nothing*"],
+        );
+    }
+
+    #[test]
+    fn only_the_members_are_rewritten() {
+        let root = tempfile::tempdir().unwrap();
+        add_crate(root.path(), "day01");
+        let manifest = "\
+# The quests of the current event
+[workspace]
+resolver = \"2\"
+members = [\"utils\", \"day*\"]
+
+[profile.release]
+debug = true # for profiling
+";
+        fs::write(root.path().join("Cargo.toml"), manifest).unwrap();
+
+        register_member(root.path(), "day02").unwrap();
+        assert_eq!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap(), "\
+# The quests of the current event
+[workspace]
+resolver = \"2\"
+members = [
+    \"day01\",
+    \"day02\",
+    \"utils\",
+]
+
+[profile.release]
+debug = true # for profiling
+");
+    }
+
+    #[test]
+    fn adds_a_workspace_table() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("Cargo.toml"), "[package]\nname = \"codes\"\n").unwrap();
+        register_member(root.path(), "day01").unwrap();
+        let manifest = fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert!(manifest.starts_with("[package]\nname = \"codes\"\n"));
+        assert!(manifest.contains("[workspace]\nmembers = [\n    \"day01\",\n]\n"));
+    }
+}
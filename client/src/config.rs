@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use utils::CURRENT_EVENT;
+
+use crate::ClientError;
+
+// The config file holds the session cookie, so it should never be committed, e.g.
+//
+// session = "0123456789abcdef"
+// base_url = "https://everybody.codes"
+// assets_url = "https://everybody-codes.b-cdn.net"
+// event = 2025
+//
+// Only the session is required. The event is the one the runner runs without --event, the inputs
+// and answers of a quest always belong to the event of the quest itself.
+pub const CONFIG_FILE: &str = "everybody-codes.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: String,
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+    #[serde(default = "default_assets_url")]
+    pub assets_url: String,
+    #[serde(default = "default_event")]
+    pub event: u16,
+}
+
+fn default_base_url() -> String {
+    String::from("https://everybody.codes")
+}
+
+fn default_assets_url() -> String {
+    String::from("https://everybody-codes.b-cdn.net")
+}
+
+fn default_event() -> u16 {
+    CURRENT_EVENT
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config, ClientError> {
+        let text = fs::read_to_string(path).map_err(|e| ClientError::Io(path.to_path_buf(), e))?;
+        Config::parse(&text, path)
+    }
+
+    fn parse(text: &str, path: &Path) -> Result<Config, ClientError> {
+        let mut config: Config = toml::from_str(text).map_err(|e| ClientError::Config(path.to_path_buf(), e))?;
+        // Trailing slashes would lead to double slashes in the urls
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+        config.assets_url = config.assets_url.trim_end_matches('/').to_string();
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        let config = Config::parse("session = \"abc\"", Path::new(CONFIG_FILE)).unwrap();
+        assert_eq!(config.session, "abc");
+        assert_eq!(config.base_url, "https://everybody.codes");
+        assert_eq!(config.assets_url, "https://everybody-codes.b-cdn.net");
+        assert_eq!(config.event, 2025);
+    }
+
+    #[test]
+    fn overrides() {
+        let text = "session = \"abc\"\nbase_url = \"http://localhost:8080/\"\nassets_url = \"http://localhost:8081\"\nevent = 2024";
+        let config = Config::parse(text, Path::new(CONFIG_FILE)).unwrap();
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.assets_url, "http://localhost:8081");
+        assert_eq!(config.event, 2024);
+    }
+
+    #[test]
+    fn missing_session() {
+        let result = Config::parse("event = 2024", Path::new(CONFIG_FILE));
+        assert!(matches!(result, Err(ClientError::Config(..))));
+    }
+}
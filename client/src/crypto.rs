@@ -0,0 +1,54 @@
+use aes::Aes256;
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockModeDecrypt, KeyIvInit};
+
+// The notes are encrypted with AES-256 in CBC mode, where the key of a part is used as is and
+// its first 16 bytes form the initialization vector
+pub fn decrypt(notes: &str, key: &str) -> Option<String> {
+    let key = key.as_bytes();
+    if key.len() != 32 {
+        return None;
+    }
+    let mut buffer = hex::decode(notes.trim()).ok()?;
+
+    let decryptor = cbc::Decryptor::<Aes256>::new_from_slices(key, &key[..16]).ok()?;
+    let plain = decryptor.decrypt_padded::<Pkcs7>(&mut buffer).ok()?;
+    String::from_utf8(plain.to_vec()).ok()
+}
+
+#[cfg(test)]
+pub fn encrypt(text: &str, key: &str) -> String {
+    use aes::cipher::BlockModeEncrypt;
+
+    let key = key.as_bytes();
+    let mut buffer = text.as_bytes().to_vec();
+    buffer.resize(text.len() + 16 - text.len() % 16, 0);
+    let encryptor = cbc::Encryptor::<Aes256>::new_from_slices(key, &key[..16]).unwrap();
+    let cipher = encryptor.encrypt_padded::<Pkcs7>(&mut buffer, text.len()).unwrap();
+    hex::encode(cipher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "0123456789abcdefghijklmnopqrstuv";
+
+    #[test]
+    fn round_trip() {
+        let notes = encrypt("Fyrryn,Elarzris\n\nR3,L2", KEY);
+        assert_eq!(decrypt(&notes, KEY).as_deref(), Some("Fyrryn,Elarzris\n\nR3,L2"));
+    }
+
+    #[test]
+    fn wrong_key() {
+        let notes = encrypt("some notes", KEY);
+        assert_ne!(decrypt(&notes, "vutsrqponmlkjihgfedcba9876543210").as_deref(), Some("some notes"));
+        assert_eq!(decrypt(&notes, "too short"), None);
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert_eq!(decrypt("not hex", KEY), None);
+    }
+}
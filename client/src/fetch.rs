@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use utils::input_path;
+
+use crate::{Client, ClientError, Keys, decrypt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    // The input was already downloaded before
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+    // The previous part has not been solved yet, so there is no key for this part
+    Locked,
+}
+
+pub fn fetch_inputs(client: &Client, event: u16, day: u8, parts: &[u8], root: &Path) -> Result<Vec<(u8, Fetched)>, ClientError> {
+    // The keys and notes are only requested once, and only if an input is missing
+    let mut keys: Option<Keys> = None;
+    let mut notes = None;
+    let mut fetched = vec![];
+
+    for &part in parts {
+        let path = root.join(input_path(event, day, part));
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            fetched.push((part, Fetched::Cached(path)));
+            continue;
+        }
+
+        if keys.is_none() {
+            keys = Some(client.keys(event, day)?);
+        }
+        let Some(key) = keys.as_ref().and_then(|keys| keys.get(part)) else {
+            fetched.push((part, Fetched::Locked));
+            continue;
+        };
+
+        if notes.is_none() {
+            let seed = client.seed()?;
+            notes = Some(client.notes(event, day, seed)?);
+        }
+        let encrypted = notes.as_ref()
+            .and_then(|notes| notes.get(&part.to_string()))
+            .ok_or_else(|| ClientError::InvalidResponse(String::from("notes"), format!("no notes for part {}", part)))?;
+        let input = decrypt(encrypted, key).ok_or(ClientError::Decrypt(part))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_path_buf(), e))?;
+        }
+        fs::write(&path, input).map_err(|e| ClientError::Io(path.clone(), e))?;
+        fetched.push((part, Fetched::Downloaded(path)));
+    }
+
+    Ok(fetched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::encrypt;
+    use crate::stub::StubServer;
+
+    const KEY_1: &str = "0123456789abcdefghijklmnopqrstuv";
+    const KEY_2: &str = "vutsrqponmlkjihgfedcba9876543210";
+
+    fn notes() -> String {
+        format!(
+            "{{\"1\": \"{}\", \"2\": \"{}\", \"3\": \"{}\"}}",
+            encrypt("first notes", KEY_1), encrypt("second notes", KEY_2), encrypt("third notes", KEY_1),
+        )
+    }
+
+    #[test]
+    fn downloads_unlocked_parts() {
+        let server = StubServer::start(vec![
+            ("GET", String::from("/api/user/me"), 200, String::from("{\"seed\": 42, \"name\": \"someone\"}")),
+            ("GET", String::from("/assets/2025/3/input/42.json"), 200, notes()),
+            ("GET", String::from("/api/event/2025/quest/3"), 200, format!("{{\"key1\": \"{}\", \"key2\": \"{}\"}}", KEY_1, KEY_2)),
+        ]);
+        let root = tempfile::tempdir().unwrap();
+
+        let fetched = fetch_inputs(&server.client(), 2025, 3, &[1, 2, 3], root.path()).unwrap();
+        let first = root.path().join("inputs/day03pt1.txt");
+        let second = root.path().join("inputs/day03pt2.txt");
+        assert_eq!(fetched, vec![
+            (1, Fetched::Downloaded(first.clone())),
+            (2, Fetched::Downloaded(second.clone())),
+            (3, Fetched::Locked),
+        ]);
+        assert_eq!(fs::read_to_string(first).unwrap(), "first notes");
+        assert_eq!(fs::read_to_string(second).unwrap(), "second notes");
+
+        let requests = server.requests();
+        let paths: Vec<&str> = requests.iter().map(|request| request.path.as_str()).collect();
+        assert_eq!(paths, vec!["/api/event/2025/quest/3", "/api/user/me", "/assets/2025/3/input/42.json"]);
+        assert!(requests.iter().all(|request| request.method == "GET"));
+        assert!(requests.iter().all(|request| request.cookie.as_deref() == Some("everybody-codes=secret")));
+    }
+
+    #[test]
+    fn event_of_the_quest() {
+        // The config event is only a default, the quest decides where its input comes from
+        let server = StubServer::start(vec![
+            ("GET", String::from("/api/user/me"), 200, String::from("{\"seed\": 42}")),
+            ("GET", String::from("/assets/2024/3/input/42.json"), 200, notes()),
+            ("GET", String::from("/api/event/2024/quest/3"), 200, format!("{{\"key1\": \"{}\"}}", KEY_1)),
+        ]);
+        let root = tempfile::tempdir().unwrap();
+
+        let fetched = fetch_inputs(&server.client(), 2024, 3, &[1], root.path()).unwrap();
+        let path = root.path().join("event2024/inputs/quest03pt1.txt");
+        assert_eq!(fetched, vec![(1, Fetched::Downloaded(path.clone()))]);
+        assert_eq!(fs::read_to_string(path).unwrap(), "first notes");
+    }
+
+    #[test]
+    fn cached_inputs_are_not_requested() {
+        let server = StubServer::start(vec![]);
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("inputs")).unwrap();
+        fs::write(root.path().join("inputs/day07pt1.txt"), "already there").unwrap();
+
+        let fetched = fetch_inputs(&server.client(), 2025, 7, &[1], root.path()).unwrap();
+        assert_eq!(fetched, vec![(1, Fetched::Cached(root.path().join("inputs/day07pt1.txt")))]);
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn expired_session() {
+        let server = StubServer::start(vec![
+            ("GET", String::from("/api/user/me"), 200, String::from("{\"seed\": 0}")),
+            ("GET", String::from("/api/event/2025/quest/1"), 200, format!("{{\"key1\": \"{}\"}}", KEY_1)),
+        ]);
+        let root = tempfile::tempdir().unwrap();
+
+        let result = fetch_inputs(&server.client(), 2025, 1, &[1], root.path());
+        assert!(matches!(result, Err(ClientError::NotLoggedIn)));
+    }
+
+    #[test]
+    fn error_status() {
+        let server = StubServer::start(vec![]);
+        let root = tempfile::tempdir().unwrap();
+
+        let result = fetch_inputs(&server.client(), 2025, 1, &[1], root.path());
+        assert!(matches!(result, Err(ClientError::Status(_, 404))));
+    }
+}
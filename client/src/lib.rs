@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use ureq::Agent;
+
+mod config;
+mod crypto;
+mod fetch;
+#[cfg(test)]
+mod stub;
+mod submit;
+
+pub use config::{CONFIG_FILE, Config};
+pub use crypto::decrypt;
+pub use fetch::{Fetched, fetch_inputs};
+pub use submit::{HISTORY_FILE, History, Submission, Submitted, Verdict, submit_answer};
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(PathBuf, io::Error),
+    Config(PathBuf, toml::de::Error),
+    History(PathBuf, toml::de::Error),
+    Http(String, ureq::Error),
+    Status(String, u16),
+    InvalidResponse(String, String),
+    // The session cookie is missing or has expired
+    NotLoggedIn,
+    Decrypt(u8),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Io(path, e) => write!(f, "could not access {}: {}", path.display(), e),
+            ClientError::Config(path, e) => write!(f, "could not parse config file {}: {}", path.display(), e),
+            ClientError::History(path, e) => write!(f, "could not parse history file {}: {}", path.display(), e),
+            ClientError::Http(url, e) => write!(f, "request to {} failed: {}", url, e),
+            ClientError::Status(url, status) => write!(f, "request to {} failed with status {}", url, status),
+            ClientError::InvalidResponse(url, reason) => write!(f, "invalid response from {}: {}", url, reason),
+            ClientError::NotLoggedIn => write!(f, "not logged in, check the session in {}", CONFIG_FILE),
+            ClientError::Decrypt(part) => write!(f, "could not decrypt the notes of part {}", part),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Io(_, e) => Some(e),
+            ClientError::Config(_, e) => Some(e),
+            ClientError::History(_, e) => Some(e),
+            ClientError::Http(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// The keys of the parts that have been unlocked so far
+#[derive(Debug, Default, Deserialize)]
+pub struct Keys {
+    key1: Option<String>,
+    key2: Option<String>,
+    key3: Option<String>,
+}
+
+impl Keys {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.key1.as_deref(),
+            2 => self.key2.as_deref(),
+            3 => self.key3.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub struct Client {
+    config: Config,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Client {
+        // Error statuses are turned into a ClientError by the client itself
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Client { config, agent }
+    }
+
+    fn cookie(&self) -> String {
+        format!("everybody-codes={}", self.config.session)
+    }
+
+    fn get<T: DeserializeOwned>(&self, url: String) -> Result<T, ClientError> {
+        let mut response = self.agent.get(&url)
+            .header("Cookie", self.cookie())
+            .call()
+            .map_err(|e| ClientError::Http(url.clone(), e))?;
+        if !response.status().is_success() {
+            return Err(ClientError::Status(url, response.status().as_u16()));
+        }
+        response.body_mut()
+            .read_json::<T>()
+            .map_err(|e| ClientError::InvalidResponse(url, e.to_string()))
+    }
+
+    pub fn seed(&self) -> Result<u64, ClientError> {
+        #[derive(Deserialize)]
+        struct User {
+            seed: u64,
+        }
+
+        let user: User = self.get(format!("{}/api/user/me", self.config.base_url))?;
+        if user.seed == 0 {
+            return Err(ClientError::NotLoggedIn);
+        }
+        Ok(user.seed)
+    }
+
+    pub fn notes(&self, event: u16, quest: u8, seed: u64) -> Result<HashMap<String, String>, ClientError> {
+        // The encrypted notes of every part, keyed by the part number
+        self.get(format!("{}/assets/{}/{}/input/{}.json", self.config.assets_url, event, quest, seed))
+    }
+
+    pub fn keys(&self, event: u16, quest: u8) -> Result<Keys, ClientError> {
+        self.get(format!("{}/api/event/{}/quest/{}", self.config.base_url, event, quest))
+    }
+
+    pub fn submit(&self, event: u16, quest: u8, part: u8, answer: &str) -> Result<Verdict, ClientError> {
+        #[derive(Deserialize)]
+        struct Response {
+            correct: bool,
+        }
+
+        let url = format!("{}/api/event/{}/quest/{}/part/{}/answer", self.config.base_url, event, quest, part);
+        let mut response = self.agent.post(&url)
+            .header("Cookie", self.cookie())
+            .send_json(serde_json::json!({ "answer": answer }))
+            .map_err(|e| ClientError::Http(url.clone(), e))?;
+        // Answers sent too soon after a wrong one are rejected as locked
+        match response.status().as_u16() {
+            423 => return Ok(Verdict::TooEarly),
+            status if !response.status().is_success() => return Err(ClientError::Status(url, status)),
+            _ => (),
+        }
+        let response: Response = response.body_mut()
+            .read_json()
+            .map_err(|e| ClientError::InvalidResponse(url, e.to_string()))?;
+        Ok(if response.correct { Verdict::Correct } else { Verdict::Wrong })
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::{Client, Config};
+
+// Minimal HTTP server for the tests, which answers every request with the status and body of the
+// route matching its method and path, or with a 404, and records the requests it received
+
+type Route = (&'static str, String, u16, String);
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    pub fn start(routes: Vec<Route>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &routes, &recorded);
+            }
+        });
+
+        StubServer { url, requests }
+    }
+
+    // A client logged in with the session "secret" that sends all requests to this server
+    pub fn client(&self) -> Client {
+        Client::new(Config {
+            session: String::from("secret"),
+            base_url: self.url.clone(),
+            assets_url: self.url.clone(),
+            event: 2025,
+        })
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(mut stream: TcpStream, routes: &[Route], recorded: &Mutex<Vec<Request>>) -> Option<()> {
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut cookie = None;
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.trim().to_string()),
+            "content-length" => length = value.trim().parse().ok()?,
+            _ => (),
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    let body = String::from_utf8_lossy(&body).to_string();
+
+    // The request is recorded before responding, so the client never finishes before it is
+    recorded.lock().unwrap().push(Request { method: method.clone(), path: path.clone(), cookie, body });
+
+    let (status, response) = routes.iter()
+        .find(|(route_method, route_path, _, _)| *route_method == method && *route_path == path)
+        .map_or((404, ""), |(_, _, status, body)| (*status, body.as_str()));
+    write!(
+        stream,
+        "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, response.len(), response,
+    ).ok()
+}
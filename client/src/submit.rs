@@ -0,0 +1,204 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Client, ClientError};
+
+// Every answer that is posted is recorded in the history file, e.g.
+//
+// [[submission]]
+// event = 2025
+// day = 5
+// part = 1
+// answer = "1234"
+// verdict = "wrong"
+// time = 1760774400
+pub const HISTORY_FILE: &str = "submissions.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    // Answers are only accepted again some time after a wrong answer, this one was not checked
+    TooEarly,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooEarly => write!(f, "too early, try again later"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Submission {
+    pub event: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub time: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<History, ClientError> {
+        // Nothing has been submitted yet if there is no history file
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(ClientError::Io(path.to_path_buf(), e)),
+        };
+        toml::from_str(&text).map_err(|e| ClientError::History(path.to_path_buf(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ClientError> {
+        let text = toml::to_string(self)
+            .map_err(|e| ClientError::Io(path.to_path_buf(), io::Error::other(e)))?;
+        fs::write(path, text).map_err(|e| ClientError::Io(path.to_path_buf(), e))
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    fn of_part(&self, event: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter()
+            .filter(move |submission| submission.event == event && submission.day == day && submission.part == part)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submitted {
+    Posted(Verdict),
+    // The answers below are not posted again
+    AlreadyCorrect(String),
+    KnownWrong,
+}
+
+pub fn submit_answer(client: &Client, history: &Path, event: u16, day: u8, part: u8, answer: &str) -> Result<Submitted, ClientError> {
+    let mut submissions = History::load(history)?;
+    if let Some(correct) = submissions.of_part(event, day, part).find(|submission| submission.verdict == Verdict::Correct) {
+        return Ok(Submitted::AlreadyCorrect(correct.answer.clone()));
+    }
+    let known_wrong = submissions.of_part(event, day, part)
+        .any(|submission| submission.answer == answer && submission.verdict == Verdict::Wrong);
+    if known_wrong {
+        return Ok(Submitted::KnownWrong);
+    }
+
+    let verdict = client.submit(event, day, part, answer)?;
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    submissions.submissions.push(Submission { event, day, part, answer: answer.to_string(), verdict, time });
+    submissions.save(history)?;
+    Ok(Submitted::Posted(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    const PATH: &str = "/api/event/2025/quest/5/part/2/answer";
+
+    #[test]
+    fn records_verdicts() {
+        let server = StubServer::start(vec![
+            ("POST", String::from(PATH), 200, String::from("{\"correct\": false, \"lengthCorrect\": true}")),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join(HISTORY_FILE);
+
+        let submitted = submit_answer(&server.client(), &history, 2025, 5, 2, "1234").unwrap();
+        assert_eq!(submitted, Submitted::Posted(Verdict::Wrong));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].cookie.as_deref(), Some("everybody-codes=secret"));
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body, serde_json::json!({ "answer": "1234" }));
+
+        let recorded = History::load(&history).unwrap();
+        assert_eq!(recorded.submissions().len(), 1);
+        assert_eq!(recorded.submissions()[0].answer, "1234");
+        assert_eq!(recorded.submissions()[0].verdict, Verdict::Wrong);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let server = StubServer::start(vec![
+            ("POST", String::from(PATH), 200, String::from("{\"correct\": false}")),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join(HISTORY_FILE);
+
+        submit_answer(&server.client(), &history, 2025, 5, 2, "1234").unwrap();
+        let submitted = submit_answer(&server.client(), &history, 2025, 5, 2, "1234").unwrap();
+        assert_eq!(submitted, Submitted::KnownWrong);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn skips_solved_parts() {
+        let server = StubServer::start(vec![
+            ("POST", String::from(PATH), 200, String::from("{\"correct\": true}")),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join(HISTORY_FILE);
+
+        assert_eq!(submit_answer(&server.client(), &history, 2025, 5, 2, "42").unwrap(), Submitted::Posted(Verdict::Correct));
+        let submitted = submit_answer(&server.client(), &history, 2025, 5, 2, "43").unwrap();
+        assert_eq!(submitted, Submitted::AlreadyCorrect(String::from("42")));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn answers_per_event() {
+        let server = StubServer::start(vec![
+            ("POST", String::from(PATH), 200, String::from("{\"correct\": true}")),
+            ("POST", String::from("/api/event/2024/quest/5/part/2/answer"), 200, String::from("{\"correct\": false}")),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join(HISTORY_FILE);
+
+        submit_answer(&server.client(), &history, 2025, 5, 2, "42").unwrap();
+        // Solving day 5 of one event does not solve it for another
+        let submitted = submit_answer(&server.client(), &history, 2024, 5, 2, "42").unwrap();
+        assert_eq!(submitted, Submitted::Posted(Verdict::Wrong));
+        let events: Vec<u16> = History::load(&history).unwrap().submissions().iter().map(|submission| submission.event).collect();
+        assert_eq!(events, vec![2025, 2024]);
+    }
+
+    #[test]
+    fn too_early() {
+        let server = StubServer::start(vec![
+            ("POST", String::from(PATH), 423, String::new()),
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join(HISTORY_FILE);
+
+        let submitted = submit_answer(&server.client(), &history, 2025, 5, 2, "1234").unwrap();
+        assert_eq!(submitted, Submitted::Posted(Verdict::TooEarly));
+        // The answer was not checked, so it can be submitted again
+        let submitted = submit_answer(&server.client(), &history, 2025, 5, 2, "1234").unwrap();
+        assert_eq!(submitted, Submitted::Posted(Verdict::TooEarly));
+    }
+}
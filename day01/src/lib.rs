@@ -0,0 +1,239 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span, Turn, list, turn};
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    type Input = NameSelector;
+    type Answer = String;
+
+    fn parse(&self, input: &str) -> Result<NameSelector, ParseError> {
+        NameSelector::parse(input)
+    }
+
+    fn part1(&self, selector: &NameSelector) -> String {
+        selector.select(Mode::Clamp)
+    }
+
+    fn part2(&self, selector: &NameSelector) -> String {
+        selector.select(Mode::Wrap)
+    }
+
+    fn part3(&self, selector: &NameSelector) -> String {
+        selector.select(Mode::Swap)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub turn: Turn,
+    pub steps: usize,
+}
+
+// How the moves select a name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // Walk along the names, stopping at the first and the last one
+    Clamp,
+    // Walk along the names, going around at the ends
+    Wrap,
+    // Count from the first name and swap the name counted to with the first one
+    Swap,
+    // Count from the first name and reverse the names up to the one counted to, so it comes first
+    Reverse,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 4] = [Mode::Clamp, Mode::Wrap, Mode::Swap, Mode::Reverse];
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub position: usize,
+    pub name: String,
+}
+
+// When a name is selected during a walk. Step 0 is the selection before the first move, step n the
+// one after the first n moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selections {
+    pub first: Option<usize>,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameSelector {
+    names: Vec<String>,
+    moves: Vec<Move>,
+}
+
+impl NameSelector {
+    pub fn parse(input: &str) -> Result<NameSelector, ParseError> {
+        // The names are on the first line and the moves on the next one
+        let mut lines = Span::lines(input);
+        let names = lines.next()
+            .ok_or_else(|| Span::end(input).error("expected a line of names"))?;
+        let instructions = lines.next()
+            .ok_or_else(|| Span::end(input).error("expected a line of instructions"))?;
+
+        let names = list(names, ",", |name| {
+            if name.text.is_empty() {
+                Err(name.error("expected a name"))
+            }
+            else {
+                Ok(name.text.to_string())
+            }
+        })?;
+        let moves = list(instructions, ",", |instruction| {
+            let (turn, steps) = turn(instruction)?;
+            Ok(Move { turn, steps })
+        })?;
+        Ok(NameSelector { names, moves })
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn select(&self, mode: Mode) -> String {
+        let (names, position) = self.walk(mode, |_, _| ());
+        names[position].clone()
+    }
+
+    // The selected name before the first move and after every move
+    pub fn trace(&self, mode: Mode) -> Vec<Step> {
+        let mut steps = vec![Step { position: 0, name: self.names[0].clone() }];
+        self.walk(mode, |names, position| steps.push(Step { position, name: names[position].clone() }));
+        steps
+    }
+
+    pub fn selections(&self, name: &str, mode: Mode) -> Selections {
+        let mut selections = Selections { first: None, count: 0 };
+        let mut step = 0;
+        let mut record = |names: &[String], position: usize| {
+            if names[position] == name {
+                selections.first.get_or_insert(step);
+                selections.count += 1;
+            }
+            step += 1;
+        };
+
+        record(&self.names, 0);
+        self.walk(mode, record);
+        selections
+    }
+
+    // The selections of a name in every mode, to find out where a walk first went to it
+    pub fn find(&self, name: &str) -> Vec<(Mode, Selections)> {
+        Mode::ALL.iter()
+            .map(|&mode| (mode, self.selections(name, mode)))
+            .collect()
+    }
+
+    fn walk(&self, mode: Mode, mut visit: impl FnMut(&[String], usize)) -> (Vec<String>, usize) {
+        // Only the swap and reverse modes change the order of the names
+        let mut names = self.names.clone();
+        let length = names.len();
+        let mut position: usize = 0;
+
+        for step in &self.moves {
+            position = match mode {
+                Mode::Clamp => match step.turn {
+                    Turn::Left => position.saturating_sub(step.steps),
+                    Turn::Right => (position + step.steps).min(length - 1),
+                },
+                Mode::Wrap => count(position, step, length),
+                Mode::Swap => {
+                    names.swap(0, count(0, step, length));
+                    0
+                }
+                Mode::Reverse => {
+                    names[..=count(0, step, length)].reverse();
+                    0
+                }
+            };
+            visit(&names, position);
+        }
+        (names, position)
+    }
+}
+
+fn count(from: usize, step: &Move, length: usize) -> usize {
+    // The position a move ends at, going around at the ends
+    let steps = step.steps % length;
+    match step.turn {
+        Turn::Left => (from + length - steps) % length,
+        Turn::Right => (from + steps) % length,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Vyrdax,Drakzyph,Fyrryn,Elarzris\n\nR3,L2,R3,L1";
+
+    #[test]
+    fn part_1_clamps_at_the_ends() {
+        let selector = Day01.parse(EXAMPLE).unwrap();
+        assert_eq!(Day01.part1(&selector), "Fyrryn");
+    }
+
+    #[test]
+    fn part_2_wraps_around() {
+        let selector = Day01.parse(EXAMPLE).unwrap();
+        assert_eq!(Day01.part2(&selector), "Elarzris");
+    }
+
+    #[test]
+    fn part_3_swaps_with_the_first_name() {
+        let selector = Day01.parse("Vyrdax,Drakzyph,Fyrryn,Elarzris\n\nR3,L2,R3,L3").unwrap();
+        assert_eq!(Day01.part3(&selector), "Drakzyph");
+    }
+
+    #[test]
+    fn position_is_clamped() {
+        let selector = NameSelector::parse("A,B,C,D\nR2,L5,R5,L1").unwrap();
+        let positions: Vec<usize> = selector.trace(Mode::Clamp).iter().map(|step| step.position).collect();
+        assert_eq!(positions, vec![0, 2, 0, 3, 2]);
+    }
+
+    #[test]
+    fn traces_every_move() {
+        let selector = NameSelector::parse(EXAMPLE).unwrap();
+        let names = |mode| -> Vec<String> {
+            selector.trace(mode).into_iter().map(|step| step.name).collect()
+        };
+        assert_eq!(names(Mode::Wrap), vec!["Vyrdax", "Elarzris", "Drakzyph", "Vyrdax", "Elarzris"]);
+        assert_eq!(names(Mode::Reverse), vec!["Vyrdax", "Elarzris", "Drakzyph", "Vyrdax", "Drakzyph"]);
+        assert_eq!(selector.select(Mode::Reverse), "Drakzyph");
+    }
+
+    #[test]
+    fn finds_the_first_selection_of_a_name() {
+        let selector = NameSelector::parse(EXAMPLE).unwrap();
+        assert_eq!(selector.find("Elarzris"), vec![
+            (Mode::Clamp, Selections { first: Some(1), count: 2 }),
+            (Mode::Wrap, Selections { first: Some(1), count: 2 }),
+            (Mode::Swap, Selections { first: Some(1), count: 1 }),
+            (Mode::Reverse, Selections { first: Some(1), count: 1 }),
+        ]);
+        assert_eq!(selector.selections("Vyrdax", Mode::Wrap), Selections { first: Some(0), count: 2 });
+        assert_eq!(selector.selections("Fyrryn", Mode::Reverse), Selections { first: None, count: 0 });
+    }
+
+    #[test]
+    fn malformed_instructions_are_reported() {
+        let error = Day01.parse("Vyrdax,Drakzyph\n\nR3,X2").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 4: expected L or R, found \"X2\"");
+        let error = Day01.parse("Vyrdax,Drakzyph").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Day01.parse("Vyrdax,,Drakzyph\nR1").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 8: expected a name");
+    }
+}
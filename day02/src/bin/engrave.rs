@@ -0,0 +1,71 @@
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process::ExitCode;
+
+use day02::{Day02, Engraver, ascii, write_pgm};
+use utils::{Solver, input_path, resolve, set_root, try_read_input};
+
+const USAGE: &str = "\
+Usage: engrave [--part K] [--pgm PATH] [--root PATH]
+
+Draws the engraving of part 2 or 3 as ASCII art, to check it when the count disagrees.
+
+Options:
+    --part K       Engrave the points of part K, 2 by default
+    --pgm PATH     Also write the engraving to PATH as graymap, with the cycle in which a point
+                   escapes as its intensity
+    --root PATH    Read the input from PATH instead of the workspace";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut part = 2;
+    let mut pgm = None;
+    for flag in args.chunks(2) {
+        match flag {
+            [flag, value] if flag == "--part" && (value == "2" || value == "3") => part = value.parse().unwrap(),
+            [flag, value] if flag == "--pgm" => pgm = Some(value.clone()),
+            [flag, value] if flag == "--root" => {
+                set_root(value);
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let path = resolve(input_path(Day02::EVENT, Day02::DAY, part));
+    let top_left = match try_read_input(&path).map(|input| Day02.parse(&input)) {
+        Ok(Ok(top_left)) => top_left,
+        Ok(Err(e)) => {
+            eprintln!("error: invalid input {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let escapes = Engraver::for_part(part, top_left)
+        .and_then(|engraver| Ok((engraver, engraver.escapes()?)));
+    let (engraver, escapes) = match escapes {
+        Ok(escapes) => escapes,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{}", ascii(&escapes.map(Option::is_none)));
+
+    if let Some(pgm) = pgm {
+        let written = File::create(&pgm)
+            .and_then(|file| write_pgm(&escapes, engraver.iterations, BufWriter::new(file)));
+        if let Err(e) = written {
+            eprintln!("error: could not write {}: {}", pgm, e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul};
+use std::str::FromStr;
+
+use utils::parse::{ParseError, Span, numbers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Complex {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Complex {
+        Complex { x, y }
+    }
+
+    // Either [X,Y] or a named number like A=[X,Y]
+    pub fn parse(span: Span) -> Result<Complex, ParseError> {
+        let span = span.trim();
+        let value = span.split_once("=").map_or(span, |(_, value)| value);
+        let inner = value.delimited("[", "]")?;
+        match numbers(inner)?.as_slice() {
+            &[x, y] => Ok(Complex { x, y }),
+            _ => Err(inner.error(format!("expected two numbers, found \"{}\"", inner.text))),
+        }
+    }
+
+    // The checked operations return None on overflow, or when dividing by zero
+    pub fn checked_add(self, other: Complex) -> Option<Complex> {
+        Some(Complex { x: self.x.checked_add(other.x)?, y: self.y.checked_add(other.y)? })
+    }
+
+    pub fn checked_mul(self, other: Complex) -> Option<Complex> {
+        // [X1,Y1] * [X2,Y2] = [X1 * X2 - Y1 * Y2, X1 * Y2 + Y1 * X2]
+        let x = self.x.checked_mul(other.x)?.checked_sub(self.y.checked_mul(other.y)?)?;
+        let y = self.x.checked_mul(other.y)?.checked_add(self.y.checked_mul(other.x)?)?;
+        Some(Complex { x, y })
+    }
+
+    pub fn checked_div(self, other: Complex) -> Option<Complex> {
+        Some(Complex { x: self.x.checked_div(other.x)?, y: self.y.checked_div(other.y)? })
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        // [X1,Y1] / [X2,Y2] = [X1 / X2, Y1 / Y2] using integer division, truncating towards zero
+        Complex { x: self.x / other.x, y: self.y / other.y }
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{},{}]", self.x, self.y)
+    }
+}
+
+impl FromStr for Complex {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Complex, ParseError> {
+        Complex::parse(Span::new(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Complex::new(1, 1) + Complex::new(2, 2), Complex::new(3, 3));
+        assert_eq!(Complex::new(2, 5) * Complex::new(3, 7), Complex::new(-29, 29));
+        assert_eq!(Complex::new(-10, -12) / Complex::new(2, 2), Complex::new(-5, -6));
+        assert_eq!(Complex::new(-11, -12) / Complex::new(3, 5), Complex::new(-3, -2));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(Complex::new(2, 5).checked_mul(Complex::new(3, 7)), Some(Complex::new(-29, 29)));
+        assert_eq!(Complex::new(isize::MAX, 0).checked_add(Complex::new(1, 0)), None);
+        assert_eq!(Complex::new(0, isize::MAX / 2).checked_mul(Complex::new(0, 3)), None);
+        assert_eq!(Complex::new(1, 1).checked_div(Complex::new(1, 0)), None);
+    }
+
+    #[test]
+    fn display_and_parse() {
+        assert_eq!(Complex::new(357, -862).to_string(), "[357,-862]");
+        assert_eq!("A=[35300,-64910]".parse(), Ok(Complex::new(35300, -64910)));
+        assert_eq!("[357,-862]".parse(), Ok(Complex::new(357, -862)));
+        let error = "A=[1,2,3]".parse::<Complex>().unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 4: expected two numbers, found \"1,2,3\"");
+        assert_eq!("A=(1,2)".parse::<Complex>().unwrap_err().column, 3);
+    }
+}
@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::fmt;
+
+use rayon::prelude::*;
+
+use utils::grid::Grid;
+
+use crate::Complex;
+
+// The settings of the engraving machine. The quest only changes the step between the engraved
+// points, the rest can be changed with struct update syntax to try out variants, e.g.
+//
+// Engraver { iterations: 50, ..Engraver::new(top_left)? }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Engraver {
+    pub top_left: Complex,
+    pub bottom_right: Complex,
+    pub step: usize,
+    pub iterations: usize,
+    pub divisor: Complex,
+    // Points are engraved if no component of the result leaves -bound..=bound
+    pub bound: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngraveError {
+    ZeroStep,
+    InvertedRegion(Complex, Complex),
+    Overflow,
+}
+
+impl fmt::Display for EngraveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngraveError::ZeroStep => write!(f, "the step between engraved points must not be 0"),
+            EngraveError::InvertedRegion(top_left, bottom_right) => {
+                write!(f, "the bottom right {} lies above or left of the top left {}", bottom_right, top_left)
+            }
+            EngraveError::Overflow => write!(f, "the region does not fit in memory"),
+        }
+    }
+}
+
+impl Error for EngraveError {}
+
+impl Engraver {
+    pub fn new(top_left: Complex) -> Result<Engraver, EngraveError> {
+        let bottom_right = top_left.checked_add(Complex::new(1000, 1000))
+            .ok_or(EngraveError::Overflow)?;
+        Ok(Engraver {
+            top_left,
+            bottom_right,
+            step: 1,
+            iterations: 100,
+            divisor: Complex::new(100000, 100000),
+            bound: 1000000,
+        })
+    }
+
+    // The settings of the quest: part 2 only engraves every tenth point, part 3 all of them
+    pub fn for_part(part: u8, top_left: Complex) -> Result<Engraver, EngraveError> {
+        let step = match part {
+            2 => 10,
+            3 => 1,
+            _ => panic!("Only parts 2 and 3 engrave, not part {}", part),
+        };
+        Ok(Engraver { step, ..Engraver::new(top_left)? })
+    }
+
+    // The number of columns and rows of engraved points, which fails for settings that do not
+    // describe a region
+    pub fn size(&self) -> Result<(usize, usize), EngraveError> {
+        if self.step == 0 {
+            return Err(EngraveError::ZeroStep);
+        }
+        if self.bottom_right.x < self.top_left.x || self.bottom_right.y < self.top_left.y {
+            return Err(EngraveError::InvertedRegion(self.top_left, self.bottom_right));
+        }
+        let width = (self.bottom_right.x.abs_diff(self.top_left.x) / self.step).checked_add(1);
+        let height = (self.bottom_right.y.abs_diff(self.top_left.y) / self.step).checked_add(1);
+        match (width, height) {
+            (Some(width), Some(height)) if width.checked_mul(height).is_some() => Ok((width, height)),
+            _ => Err(EngraveError::Overflow),
+        }
+    }
+
+    // The point of the cell in the given row and column, the real part runs along the columns.
+    // Inside the region the point lies between the corners, so adding the offset cannot overflow.
+    pub fn point(&self, row: usize, col: usize) -> Complex {
+        Complex::new(
+            self.top_left.x.wrapping_add_unsigned(col * self.step),
+            self.top_left.y.wrapping_add_unsigned(row * self.step),
+        )
+    }
+
+    // The cycle in which the result of a point leaves the bounds, or None if the point is engraved
+    pub fn escape(&self, point: Complex) -> Option<usize> {
+        let mut result = Complex::ZERO;
+        for cycle in 0..self.iterations {
+            // A result that overflows has certainly left the bounds
+            match self.cycle(result, point) {
+                Some(next) if self.within_bounds(next) => result = next,
+                _ => return Some(cycle),
+            }
+        }
+        None
+    }
+
+    fn cycle(&self, result: Complex, point: Complex) -> Option<Complex> {
+        result.checked_mul(result)?
+            .checked_div(self.divisor)?
+            .checked_add(point)
+    }
+
+    fn within_bounds(&self, number: Complex) -> bool {
+        (-self.bound..=self.bound).contains(&number.x) && (-self.bound..=self.bound).contains(&number.y)
+    }
+
+    // Whether each point of the region is engraved
+    pub fn engrave(&self) -> Result<Grid<bool>, EngraveError> {
+        self.evaluate(|point| self.escape(point).is_none())
+    }
+
+    // The cycle in which each point of the region escapes, as used for the intensity of a graymap
+    pub fn escapes(&self) -> Result<Grid<Option<usize>>, EngraveError> {
+        self.evaluate(|point| self.escape(point))
+    }
+
+    fn evaluate<T: Send>(&self, value: impl Fn(Complex) -> T + Sync) -> Result<Grid<T>, EngraveError> {
+        let (width, height) = self.size()?;
+        let cells = (0..height).into_par_iter()
+            .flat_map_iter(|row| (0..width).map(move |col| self.point(row, col)).map(&value))
+            .collect::<Vec<T>>();
+        Ok(Grid::new(width, height, cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region() {
+        let engraver = Engraver::for_part(2, Complex::new(35300, -64910)).unwrap();
+        assert_eq!(engraver.size(), Ok((101, 101)));
+        assert_eq!(Engraver::for_part(3, Complex::ZERO).unwrap().size(), Ok((1001, 1001)));
+        assert_eq!(engraver.point(2, 1), Complex::new(35310, -64890));
+    }
+
+    #[test]
+    fn invalid_settings() {
+        let engraver = Engraver::new(Complex::ZERO).unwrap();
+        assert_eq!(Engraver { step: 0, ..engraver }.engrave(), Err(EngraveError::ZeroStep));
+        let inverted = Engraver { bottom_right: Complex::new(1000, -1), ..engraver };
+        assert_eq!(inverted.size(), Err(EngraveError::InvertedRegion(Complex::ZERO, Complex::new(1000, -1))));
+        let huge = Engraver { top_left: Complex::new(isize::MIN, 0), bottom_right: Complex::new(isize::MAX, 0), ..engraver };
+        assert_eq!(huge.size(), Err(EngraveError::Overflow));
+        assert_eq!(Engraver::new(Complex::new(0, isize::MAX - 999)), Err(EngraveError::Overflow));
+    }
+
+    #[test]
+    fn escapes() {
+        let engraver = Engraver::new(Complex::ZERO).unwrap();
+        assert_eq!(engraver.escape(Complex::new(35630, -64880)), None);
+        assert_eq!(engraver.escape(Complex::new(35460, -64910)), Some(26));
+        // Far outside the bounds after the first cycle
+        assert_eq!(engraver.escape(Complex::new(2000000, 0)), Some(0));
+    }
+
+    #[test]
+    fn bitmap_rows_follow_the_imaginary_part() {
+        let engraver = Engraver {
+            top_left: Complex::new(0, 0),
+            bottom_right: Complex::new(2, 1),
+            iterations: 1,
+            divisor: Complex::new(1, 1),
+            bound: 1,
+            step: 1,
+        };
+        let bitmap = engraver.engrave().unwrap();
+        assert_eq!(bitmap.render(|&engraved| if engraved { '#' } else { '.' }), "##.\n##.");
+    }
+}
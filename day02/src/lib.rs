@@ -0,0 +1,105 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span};
+
+mod complex;
+mod engrave;
+mod render;
+
+pub use complex::Complex;
+pub use engrave::{EngraveError, Engraver};
+pub use render::{ascii, write_pgm};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    type Input = Complex;
+    // The engraved region or the result may overflow for an input far from the origin
+    type Answer = Result<String, String>;
+
+    fn parse(&self, input: &str) -> Result<Complex, ParseError> {
+        let line = Span::lines(input).next()
+            .ok_or_else(|| Span::end(input).error("expected a complex number"))?;
+        Complex::parse(line)
+    }
+
+    fn part1(&self, complex: &Complex) -> Result<String, String> {
+        part_1(*complex).map(|result| result.to_string())
+    }
+
+    fn part2(&self, complex: &Complex) -> Result<String, String> {
+        part_2(*complex).map(|count| count.to_string())
+    }
+
+    fn part3(&self, complex: &Complex) -> Result<String, String> {
+        part_3(*complex).map(|count| count.to_string())
+    }
+}
+
+fn part_1(complex_1: Complex) -> Result<Complex, String> {
+    let mut result = Complex::ZERO;
+
+    for _i in 0..3 {
+        result = cycle(result, Complex::new(10, 10), complex_1)
+            .ok_or_else(|| String::from("the result overflows"))?;
+    }
+    Ok(result)
+}
+
+fn part_2(top_left: Complex) -> Result<usize, String> {
+    count_engraved(2, top_left).map_err(|e| e.to_string())
+}
+
+fn part_3(top_left: Complex) -> Result<usize, String> {
+    count_engraved(3, top_left).map_err(|e| e.to_string())
+}
+
+fn cycle(result: Complex, divisor: Complex, point: Complex) -> Option<Complex> {
+    result.checked_mul(result)?
+        .checked_div(divisor)?
+        .checked_add(point)
+}
+
+fn count_engraved(part: u8, top_left: Complex) -> Result<usize, EngraveError> {
+    let count = Engraver::for_part(part, top_left)?
+        .engrave()?
+        .cells()
+        .iter()
+        .filter(|&&engraved| engraved)
+        .count();
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::{Quest, QuestError};
+
+    #[test]
+    fn parses_complex_number() {
+        assert_eq!(Day02.parse("A=[35300,-64910]").unwrap(), Complex::new(35300, -64910));
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day02.part1(&Complex::new(25, 9)), Ok(String::from("[357,862]")));
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day02.part2(&Complex::new(35300, -64910)), Ok(String::from("4076")));
+    }
+
+    #[test]
+    fn part_3_example() {
+        assert_eq!(Day02.part3(&Complex::new(35300, -64910)), Ok(String::from("406954")));
+    }
+
+    #[test]
+    fn overflows_are_reported() {
+        let far = Complex::new(isize::MAX, 0);
+        assert_eq!(Day02.part1(&far), Err(String::from("the result overflows")));
+        assert_eq!(Day02.part2(&far), Err(EngraveError::Overflow.to_string()));
+        assert_eq!(Quest::part_3(&Day02, "A=[9223372036854775807,0]"), Err(QuestError::Unsolvable(EngraveError::Overflow.to_string())));
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::{self, Write};
+
+use utils::grid::Grid;
+
+pub fn ascii(bitmap: &Grid<bool>) -> String {
+    bitmap.render(|&engraved| if engraved { '#' } else { '.' })
+}
+
+// Writes a binary portable graymap, in which points are brighter the later they escape and the
+// engraved points are white. The cycles of escape are the gray values, so a single byte per point
+// is used up to 255 iterations and two bytes beyond that.
+pub fn write_pgm(escapes: &Grid<Option<usize>>, iterations: usize, mut writer: impl Write) -> io::Result<()> {
+    let white = iterations.clamp(1, u16::MAX as usize);
+    write!(writer, "P5\n{} {}\n{}\n", escapes.width(), escapes.height(), white)?;
+
+    let mut pixels = Vec::with_capacity(escapes.len() * if white < 256 { 1 } else { 2 });
+    for escape in escapes.cells() {
+        let gray = escape.map_or(white, |cycle| cycle.min(white));
+        if white < 256 {
+            pixels.push(gray as u8);
+        }
+        else {
+            pixels.extend_from_slice(&(gray as u16).to_be_bytes());
+        }
+    }
+    writer.write_all(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_art() {
+        let bitmap = Grid::new(3, 2, vec![true, false, true, false, true, false]);
+        assert_eq!(ascii(&bitmap), "#.#\n.#.");
+    }
+
+    #[test]
+    fn graymap() {
+        let escapes = Grid::new(2, 2, vec![None, Some(0), Some(3), Some(99)]);
+        let mut pgm = Vec::new();
+        write_pgm(&escapes, 100, &mut pgm).unwrap();
+        assert_eq!(pgm, [b"P5\n2 2\n100\n".as_slice(), &[100, 0, 3, 99]].concat());
+
+        let mut pgm = Vec::new();
+        write_pgm(&escapes, 300, &mut pgm).unwrap();
+        assert_eq!(pgm, [b"P5\n2 2\n300\n".as_slice(), &[1, 44, 0, 0, 0, 3, 0, 99]].concat());
+    }
+}
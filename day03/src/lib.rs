@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::Solver;
+use utils::parse::{ParseError, Span, numbers};
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<isize>;
+    type Answer = isize;
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, ParseError> {
+        numbers(Span::new(input))
+    }
+
+    fn part1(&self, numbers: &Vec<isize>) -> isize {
+        part_1(numbers)
+    }
+
+    fn part2(&self, numbers: &Vec<isize>) -> isize {
+        part_2(numbers)
+    }
+
+    fn part3(&self, numbers: &Vec<isize>) -> isize {
+        part_3(numbers) as isize
+    }
+}
+
+
+fn part_1(numbers: &[isize]) -> isize {
+    let set = get_set(numbers);
+    set.iter()
+        .sum::<isize>()
+}
+
+fn part_2(numbers: &[isize]) -> isize {
+    let set = get_set(numbers);
+    let mut vec_set = set.iter().collect::<Vec<_>>();
+    vec_set.sort();
+    vec_set[0..20].iter().fold(0, |acc, x| acc + *x)
+}
+
+fn part_3(numbers: &[isize]) -> usize {
+    // To find out how many sets are needed, find the highest number of duplicates
+    let mut frequency_map: HashMap<isize, usize> = HashMap::new();
+    numbers.iter()
+        .for_each(|x| *frequency_map.entry(*x).or_insert(0) += 1);
+    *frequency_map.values()
+        .max()
+        .expect("No maximum found")
+}
+
+fn get_set(numbers: &[isize]) -> HashSet<isize> {
+    numbers.iter()
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_2: &str = "4,51,13,64,57,51,82,57,16,88,89,48,32,49,49,2,84,65,49,43,9,13,2,3,75,72,63,48,61,14,40,77";
+
+    #[test]
+    fn part_1_example() {
+        let numbers = Day03.parse("10,5,1,10,3,8,5,2,2").unwrap();
+        assert_eq!(Day03.part1(&numbers), 29);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let numbers = Day03.parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day03.part2(&numbers), 781);
+    }
+
+    #[test]
+    fn part_3_example() {
+        let numbers = Day03.parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day03.part3(&numbers), 3);
+    }
+}
@@ -0,0 +1,99 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    type Input = f64;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<f64, ParseError> {
+        get_gear_ratio(input)
+    }
+
+    fn part1(&self, ratio: &f64) -> usize {
+        part_1(*ratio)
+    }
+
+    fn part2(&self, ratio: &f64) -> usize {
+        part_2(*ratio)
+    }
+
+    fn part3(&self, ratio: &f64) -> usize {
+        part_3(*ratio)
+    }
+}
+
+fn part_1(ratio: f64) -> usize {
+    (2025.0 * ratio) as usize
+}
+
+fn part_2(ratio: f64) -> usize {
+    (1e13 / ratio).ceil() as usize
+}
+
+fn part_3(ratio: f64) -> usize {
+    (100.0 * ratio) as usize
+}
+
+fn get_gear_ratio(input: &str) -> Result<f64, ParseError> {
+    // General function to get the total gear ratio of a setup, with or without common shafts. The
+    // gears between two shafts turn together, so only the first and the last of them count.
+    let mut ratio = 1.0;
+    let mut first = None;
+    let mut last = None;
+    for line in Span::lines(input) {
+        let (left, right) = match line.split_once("|") {
+            Ok((left, right)) => (left.trim().number::<f64>()?, Some(right.trim().number::<f64>()?)),
+            Err(_) => (line.trim().number::<f64>()?, None),
+        };
+        let teeth = *first.get_or_insert(left);
+        if let Some(right) = right {
+            ratio *= teeth / left;
+            first = Some(right);
+            last = Some(right);
+        }
+        else {
+            last = Some(left);
+        }
+    }
+    match (first, last) {
+        (Some(first), Some(last)) => Ok(ratio * first / last),
+        _ => Err(Span::end(input).error("expected a gear")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "128\n64\n32\n16\n8";
+    const EXAMPLE_2: &str = "102\n75\n50\n35\n13";
+
+    #[test]
+    fn part_1_examples() {
+        assert_eq!(Day04.part1(&Day04.parse(EXAMPLE_1).unwrap()), 32400);
+        assert_eq!(Day04.part1(&Day04.parse(EXAMPLE_2).unwrap()), 15888);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        assert_eq!(Day04.part2(&Day04.parse(EXAMPLE_1).unwrap()), 625000000000);
+        assert_eq!(Day04.part2(&Day04.parse(EXAMPLE_2).unwrap()), 1274509803922);
+    }
+
+    #[test]
+    fn part_3_examples() {
+        assert_eq!(Day04.part3(&Day04.parse("5\n5|10\n10|20\n5").unwrap()), 400);
+        assert_eq!(Day04.part3(&Day04.parse("5\n7|21\n18|36\n27|27\n10|50\n10|50\n11").unwrap()), 6818);
+    }
+
+    #[test]
+    fn malformed_gears_are_reported() {
+        let error = Day04.parse("5\n7|x1\n11").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: expected a number, found \"x1\"");
+        let error = Day04.parse("").unwrap_err();
+        assert_eq!(error.message, "expected a gear");
+    }
+}
@@ -0,0 +1,285 @@
+use std::cmp::Ordering;
+
+use utils::Solver;
+use utils::parse::{ParseError, Span, key_value, numbers};
+
+pub struct Day05;
+
+// The id of a sword and the numbers its spine is built from
+type Notes = (usize, Vec<usize>);
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    type Input = Vec<Notes>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Notes>, ParseError> {
+        let notes: Vec<Notes> = Span::lines(input)
+            .map(|line| key_value(line, Span::number, numbers))
+            .collect::<Result<_, _>>()?;
+        if notes.is_empty() {
+            return Err(Span::end(input).error("expected the notes of a sword"));
+        }
+        Ok(notes)
+    }
+
+    fn part1(&self, input: &Vec<Notes>) -> usize {
+        part_1(input)
+    }
+
+    fn part2(&self, input: &Vec<Notes>) -> usize {
+        part_2(input)
+    }
+
+    fn part3(&self, input: &Vec<Notes>) -> usize {
+        part_3(input)
+    }
+}
+
+struct Sword {
+    id: usize,
+    spine: Vec<usize>,
+    left: Vec<Option<usize>>,
+    right: Vec<Option<usize>>,
+}
+
+impl Sword {
+    fn new(id: usize) -> Sword {
+        Sword {
+            id,
+            spine: vec![],
+            left: vec![],
+            right: vec![],
+        }
+    }
+
+    fn construct_spine(mut self, numbers: &[usize]) -> Sword {
+        self.push_to_spine(numbers[0]);
+
+        for i in &numbers[1..] {
+            match i.cmp(&self.spine[self.spine.len() - 1]) {
+                Ordering::Less => {
+                    if !self.left.contains(&None) {
+                        self.push_to_spine(*i);
+                    }
+                    else {
+                        // Find the first suitable place starting from the top
+                        let mut start_idx = 0;
+                        let mut stop = false;
+                        while self.left[start_idx..].contains(&None) && !stop {
+                            let pos = self.left.iter().skip(start_idx).position(|&x| x.is_none()).unwrap() + start_idx;
+                            start_idx = pos + 1;
+                            if self.spine[pos] > *i {
+                                self.left[pos] = Some(*i);
+                                stop = true;
+                            }
+                        }
+                        if !stop {
+                            self.push_to_spine(*i);
+                        }
+                    }
+                },
+                Ordering::Equal => {
+                    self.push_to_spine(*i);
+                },
+                Ordering::Greater => {
+                    if !self.right.contains(&None) {
+                        self.push_to_spine(*i);
+                    }
+                    else {
+                        // Find the first suitable place starting from the top
+                        let mut start_idx = 0;
+                        let mut stop = false;
+                        while self.right[start_idx..].contains(&None) && !stop {
+                            let pos = self.right.iter().skip(start_idx).position(|&x| x.is_none()).unwrap() + start_idx;
+                            start_idx = pos + 1;
+                            if self.spine[pos] < *i {
+                                self.right[pos] = Some(*i);
+                                stop = true;
+                            }
+                        }
+                        if !stop {
+                            self.push_to_spine(*i);
+                        }
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    fn push_to_spine(&mut self, value: usize) {
+        self.spine.push(value);
+        self.left.push(None);
+        self.right.push(None);
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct SwordScore {
+    id: usize,
+    levels: Vec<usize>,
+    value: usize,
+}
+
+impl SwordScore {
+    fn from_sword(sword: Sword, value: usize) -> SwordScore {
+        let levels = (0..sword.spine.len())
+            .map(|i| concat_digits(sword.left[i].into_iter().chain([sword.spine[i]]).chain(sword.right[i])))
+            .collect();
+        SwordScore {
+            id: sword.id,
+            levels,
+            value
+        }
+    }
+}
+
+
+fn part_1(notes: &[Notes]) -> usize {
+    get_sword_and_value(&notes[0]).1
+}
+
+fn get_sword_and_value((id, numbers): &Notes) -> (Sword, usize) {
+    let sword = Sword::new(*id);
+    let sword = sword.construct_spine(numbers);
+    let quality = concat_digits(sword.spine.iter().copied());
+    (sword, quality)
+}
+
+// Writes the numbers one after the other, as the digits of a single number
+fn concat_digits(numbers: impl IntoIterator<Item = usize>) -> usize {
+    numbers.into_iter()
+        .fold(0, |acc, n| {
+            let digits = n.checked_ilog10().map_or(1, |log| log + 1);
+            // A number too long for usize saturates rather than wrapping around
+            acc.saturating_mul(10usize.saturating_pow(digits)).saturating_add(n)
+        })
+}
+
+fn part_2(input: &[Notes]) -> usize {
+    let mut swords_values: Vec<usize> = vec![];
+    input.iter()
+        .for_each(|notes| swords_values.push(get_sword_and_value(notes).1));
+    swords_values.sort();
+    swords_values[swords_values.len() - 1] - swords_values[0]
+}
+
+fn part_3(input: &[Notes]) -> usize {
+    let mut swords_vec: Vec<SwordScore> = vec![];
+    input.iter()
+        .for_each(|notes|  {
+            let result = get_sword_and_value(notes);
+            let sword = result.0;
+            let value = result.1;
+            let sword_score = SwordScore::from_sword(sword, value);
+            swords_vec.push(sword_score);
+        });
+
+    swords_vec.sort_by(compare_sword_scores);
+    // The sorted vec needs to be reversed to conform to the greater to lower ranking
+    swords_vec.reverse();
+    swords_vec.iter()
+        .enumerate()
+        .map(|(pos, sword_score)| sword_score.id * (pos + 1) )
+        .sum::<usize>()
+}
+
+fn compare_sword_scores(a: &SwordScore, b: &SwordScore) -> Ordering {
+    if a.value < b.value {
+        return Ordering::Less;
+    }
+
+    if a.value == b.value {
+        let length_a = a.levels.len();
+        let length_b = b.levels.len();
+        let length = std::cmp::min(length_a, length_b);
+
+        for i in 0..length {
+            if a.levels[i] == b.levels[i] {
+                continue;
+            }
+            if a.levels[i] < b.levels[i] {
+                return Ordering::Less;
+            }
+
+            if a.levels[i] > b.levels[i] {
+                return Ordering::Greater;
+            }
+        }
+
+        if a.id < b.id {
+            Ordering::Less
+        }
+
+        else {
+            Ordering::Greater
+        }
+    }
+
+    else {
+        Ordering::Greater
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let lines = Day05.parse("58:5,3,7,8,9,10,4,5,7,8,8").unwrap();
+        assert_eq!(Day05.part1(&lines), 581078);
+    }
+
+    #[test]
+    fn spine_is_built_from_the_top() {
+        let (sword, quality) = get_sword_and_value(&(58, vec![5, 3, 7, 8, 9, 10, 4, 5, 7, 8, 8]));
+        assert_eq!(sword.spine, vec![5, 8, 10, 7, 8]);
+        assert_eq!(sword.left, vec![Some(3), Some(4), Some(5), None, None]);
+        assert_eq!(sword.right, vec![Some(7), Some(9), None, Some(8), None]);
+        assert_eq!(quality, 581078);
+    }
+
+    #[test]
+    fn digits_are_concatenated() {
+        assert_eq!(concat_digits([3, 0, 10, 7]), 30107);
+        assert_eq!(concat_digits([]), 0);
+        assert_eq!(concat_digits([usize::MAX, 1]), usize::MAX);
+        assert_eq!(Day05.parse("").unwrap_err().message, "expected the notes of a sword");
+    }
+
+    #[test]
+    fn part_2_example() {
+        let lines = Day05.parse("\
+1:2,4,1,1,8,2,7,9,8,6
+2:7,9,9,3,8,3,8,8,6,8
+3:4,7,6,9,1,8,3,7,2,2
+4:6,4,2,1,7,4,5,5,5,8
+5:2,9,3,8,3,9,5,2,1,4
+6:2,4,9,6,7,4,1,7,6,8
+7:2,3,7,6,2,2,4,1,4,2
+8:5,1,5,6,8,3,1,8,3,9
+9:5,7,7,3,7,2,3,8,6,7
+10:4,1,9,3,8,5,4,3,5,5").unwrap();
+        assert_eq!(Day05.part2(&lines), 77053);
+    }
+
+    #[test]
+    fn part_3_examples() {
+        let lines = Day05.parse("\
+1:7,1,9,1,6,9,8,3,7,2
+2:6,1,9,2,9,8,8,4,3,1
+3:7,1,9,1,6,9,8,3,8,3
+4:6,1,9,2,8,8,8,4,3,1
+5:7,1,9,1,6,9,8,3,7,3
+6:6,1,9,2,8,8,8,4,3,5
+7:3,7,2,2,7,4,4,6,3,1
+8:3,7,2,2,7,4,4,6,3,7
+9:3,7,2,2,7,4,1,6,3,7").unwrap();
+        assert_eq!(Day05.part3(&lines), 260);
+
+        let lines = Day05.parse("1:7,1,9,1,6,9,8,3,7,2\n2:7,1,9,1,6,9,8,3,7,2").unwrap();
+        assert_eq!(Day05.part3(&lines), 4);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use utils::Solver;
+use utils::parse::{ParseError, Span};
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    type Input = String;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<String, ParseError> {
+        // Mentors are upper case letters and apprentices lower case ones, so every position is a
+        // single byte
+        let line = Span::lines(input).next()
+            .ok_or_else(|| Span::end(input).error("expected a line of mentors and apprentices"))?;
+        if let Some((column, c)) = line.text.chars().enumerate().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            let error = ParseError { line: line.line, column: line.column + column, message: format!("expected a letter, found '{}'", c) };
+            return Err(error);
+        }
+        Ok(line.text.to_string())
+    }
+
+    fn part1(&self, input: &String) -> usize {
+        part_1(input)
+    }
+
+    fn part2(&self, input: &String) -> usize {
+        part_2(input)
+    }
+
+    fn part3(&self, input: &String) -> usize {
+        part_3(input)
+    }
+}
+
+fn part_1(input: &str) -> usize {
+    let char_map = collect_char_map(input);
+    count_possible_mentors(&char_map, 'a')
+}
+
+fn part_2(input: &str) -> usize {
+    let char_map = collect_char_map(input);
+    let mut sum = 0;
+    let apprentices: Vec<char> = char_map.keys()
+        .filter(|k| k.is_lowercase())
+        .copied()
+        .collect();
+    for apprentice in apprentices {
+        sum += count_possible_mentors(&char_map, apprentice);
+    }
+    sum
+}
+
+fn part_3(input: &str) -> usize {
+    count_possible_mentors_repeated(input, 1000, 1000)
+}
+
+fn count_possible_mentors_repeated(input: &str, max_distance: usize, num_repeats: usize) -> usize {
+    // Repeating the structure num_repeats times leads to a contribution of num_repeats regular
+    // combinations, num_repeats - 1 left edge cases and num_repeats - 1 right edge cases
+
+    // Construct both left and right inputs, where the to be appended part should only consist of
+    // mentors and the old part only of apprentices (to avoid counting double)
+    let length_input = input.len();
+    let left_mentors = input[length_input - max_distance..]
+        .chars()
+        .map(|c| if c.is_lowercase() { '#' } else { c })
+        .collect::<String>();
+    let left_apprentices = input[..max_distance]
+        .chars()
+        .map(|c| if c.is_uppercase() { '#' } else { c })
+        .collect::<String>();
+    let input_left = [left_mentors, left_apprentices].join("");
+
+    let right_mentors = input[..max_distance]
+        .chars()
+        .map(|c| if c.is_lowercase() { '#' } else { c })
+        .collect::<String>();
+    let right_apprentices = input[length_input - max_distance..]
+        .chars()
+        .map(|c| if c.is_uppercase() { '#' } else { c })
+        .collect::<String>();
+    let input_right = [right_apprentices, right_mentors].join("");
+
+    let char_map_left = collect_char_map(&input_left);
+    let char_map_right = collect_char_map(&input_right);
+    let char_map_full = collect_char_map(input);
+    let mut sum = 0;
+    let apprentices: Vec<char> = char_map_full.keys()
+        .filter(|k| k.is_lowercase())
+        .copied()
+        .collect();
+    for apprentice in apprentices {
+        let regular= count_possible_mentors_distance_wise(&char_map_full, apprentice, max_distance as isize);
+        sum += regular * num_repeats;
+        let left = count_possible_mentors_distance_wise(&char_map_left, apprentice, max_distance as isize);
+        sum += left * (num_repeats - 1);
+        let right = count_possible_mentors_distance_wise(&char_map_right, apprentice, max_distance as isize);
+        sum += right * (num_repeats - 1);
+    }
+    sum
+}
+
+fn collect_char_map(line: &str) -> HashMap<char, Vec<usize>> {
+    let mut char_map: HashMap<char, Vec<usize>> = HashMap::new();
+    line.chars()
+        .enumerate()
+        .for_each(|(index, c)| char_map.entry(c).or_default().push(index));
+
+    char_map
+}
+
+fn count_possible_mentors(char_map: &HashMap<char, Vec<usize>>, char_type: char) -> usize {
+    let mentor_char = char_type.to_uppercase().next().unwrap();
+    let (Some(apprentices), Some(mentors)) = (char_map.get(&char_type), char_map.get(&mentor_char)) else {
+        return 0;
+    };
+
+    // Since the vectors are both naturally sorted, binary search can be used
+    apprentices.iter()
+        .map(|&pos_ap| {
+            mentors.binary_search(&pos_ap).unwrap_or_else(|pos_men| pos_men)
+        })
+        .sum()
+}
+
+fn count_possible_mentors_distance_wise(char_map: &HashMap<char, Vec<usize>>, char_type: char, max_distance: isize) -> usize {
+    let mentor_char = char_type.to_uppercase().next().unwrap();
+    let apprentices = char_map.get(&char_type);
+    let mentors = char_map.get(&mentor_char);
+
+    if apprentices.is_none() || mentors.is_none() {
+        return 0;
+    }
+
+    let distances = apprentices.unwrap().iter()
+        .flat_map(|&pos_ap| {
+            mentors.unwrap().iter()
+                .map(|&pos_men| (pos_ap as isize - pos_men as isize).abs())
+                .collect::<Vec<isize>>()
+        })
+        .collect::<Vec<isize>>();
+
+    distances.iter()
+        .filter(|&&dist| dist <= max_distance)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ABabACacBCbca";
+    const EXAMPLE_3: &str = "AABCBABCABCabcabcABCCBAACBCa";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day06.part1(&Day06.parse(EXAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day06.part2(&Day06.parse(EXAMPLE).unwrap()), 11);
+    }
+
+    #[test]
+    fn part_3_examples() {
+        assert_eq!(count_possible_mentors_repeated(EXAMPLE_3, 10, 1), 34);
+        assert_eq!(count_possible_mentors_repeated(EXAMPLE_3, 10, 2), 72);
+    }
+
+    #[test]
+    fn malformed_lines_are_reported() {
+        assert_eq!(Day06.parse("\nABab\nignored").unwrap(), "ABab");
+        let error = Day06.parse("ABa-b").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 4: expected a letter, found '-'");
+        assert_eq!(Day06.parse("\n\n").unwrap_err().message, "expected a line of mentors and apprentices");
+        // Without apprentices of a type there is nobody to mentor
+        assert_eq!(Day06.part1(&Day06.parse("ABC").unwrap()), 0);
+    }
+}
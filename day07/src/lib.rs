@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use utils::Solver;
+use utils::memo::Memo;
+use utils::parse::{ParseError, Span, list, rule};
+
+type CharMap = HashMap<char, Vec<char>>;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    type Input = (CharMap, Vec<String>);
+    type Answer = String;
+
+    fn parse(&self, input: &str) -> Result<(CharMap, Vec<String>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &(CharMap, Vec<String>)) -> String {
+        part_1(&input.0, &input.1)
+    }
+
+    fn part2(&self, input: &(CharMap, Vec<String>)) -> String {
+        part_2(&input.0, &input.1).to_string()
+    }
+
+    fn part3(&self, input: &(CharMap, Vec<String>)) -> String {
+        part_3(&input.0, &input.1).to_string()
+    }
+}
+
+fn part_1(char_map: &CharMap, names: &[String]) -> String {
+    check_names(char_map, names)[0].clone()
+}
+
+fn part_2(char_map: &CharMap, names: &[String]) -> usize {
+    sum_indices_valid_names(char_map, names)
+}
+
+fn part_3(char_map: &CharMap, prefixes: &[String]) -> usize {
+    // Old approach via HashSet worked but was very slow. Took some inspiration from
+    // https://github.com/maneatingape/everybody-codes-rust/blob/main/src/event2025/quest07.rs
+    // to optimize this.
+    // First get the valid prefixes
+    let valid_prefixes = check_names(char_map, prefixes);
+    // Filter for longer prefixes that start with another one (they will give duplicate results),
+    // and apply the recursive formula to the results. Prefixes ending in the same letter at the
+    // same length share their count, so it is only computed once.
+    let mut memo = Memo::new();
+    let valid_names_count: usize = valid_prefixes.iter()
+        .filter(|&prefix| {
+            valid_prefixes.iter()
+                .all(|other| prefix == other || !prefix.starts_with(other))
+        })
+        .map(|prefix| get_number_unique_names(char_map, &mut memo, prefix.chars().last().unwrap(), prefix.len()))
+        .sum();
+    valid_names_count
+}
+
+fn parse_input(input: &str) -> Result<(CharMap, Vec<String>), ParseError> {
+    // The names come first, followed by the rules of which letter can follow which
+    let mut lines = Span::lines(input);
+    let names = lines.next()
+        .ok_or_else(|| Span::end(input).error("expected a line of names"))?;
+    let names = list(names, ",", |name| {
+        if name.text.is_empty() {
+            Err(name.error("expected a name"))
+        }
+        else {
+            Ok(name.text.to_string())
+        }
+    })?;
+    let char_map = lines.map(|line| rule(line, Span::char, Span::char))
+        .collect::<Result<CharMap, ParseError>>()?;
+
+    Ok((char_map, names))
+}
+
+fn check_names(char_map: &HashMap<char, Vec<char>>, names: &[String]) -> Vec<String> {
+    names.iter()
+        .filter(|&name| is_valid(char_map, name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn is_valid(char_map: &HashMap<char, Vec<char>>, name: &str) -> bool {
+    let name = name.chars().collect::<Vec<char>>();
+    // A letter without a rule cannot be followed by anything
+    name.windows(2).all(|w| char_map.get(&w[0]).is_some_and(|next| next.contains(&w[1])))
+}
+
+fn sum_indices_valid_names(char_map: &HashMap<char, Vec<char>>, names: &[String]) -> usize {
+    names.iter()
+        .enumerate()
+        .filter(|(_, name)| is_valid(char_map, name))
+        .map(|(index, _)| index + 1)
+        .sum()
+}
+
+fn get_number_unique_names(char_map: &HashMap<char, Vec<char>>, memo: &mut Memo<(char, usize), usize>, last: char, size: usize) -> usize {
+    memo.get_or_insert_with((last, size), |memo| {
+        let mut total = 0;
+        if size >= 7 {
+            total += 1;
+        }
+
+        if size < 11 {
+            total + char_map.get(&last).unwrap_or(&vec![]).iter()
+                .map(|new| get_number_unique_names(char_map, memo, *new, size + 1))
+                .sum::<usize>()
+        }
+        else {
+            total
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+Oronris,Urakris,Oroneth,Uraketh
+
+r > a,i,o
+i > p,w
+n > e,r
+o > n,m
+k > f,r
+a > k
+U > r
+e > t
+O > r
+t > h";
+
+    const EXAMPLE_2: &str = "\
+Xanverax,Khargyth,Nexzeth,Helther,Braerex,Tirgryph,Kharverax
+
+r > v,e,a,g,y
+a > e,v,x,r
+e > r,x,v,t
+h > a,e,v
+g > r,y
+y > p,t
+i > v,r
+K > h
+v > e
+B > r
+t > h
+N > e
+p > h
+H > e
+l > t
+z > e
+X > a
+n > v
+x > z
+T > i";
+
+    #[test]
+    fn parses_rules_and_names() {
+        let (char_map, names) = Day07.parse(EXAMPLE_1).unwrap();
+        assert_eq!(names, vec!["Oronris", "Urakris", "Oroneth", "Uraketh"]);
+        assert_eq!(char_map[&'r'], vec!['a', 'i', 'o']);
+        assert_eq!(char_map.len(), 10);
+    }
+
+    #[test]
+    fn rules_have_single_letters() {
+        let error = Day07.parse("Oronris\n\nr > a,io").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 7: expected a single character, found \"io\"");
+    }
+
+    #[test]
+    fn names_are_not_empty() {
+        let error = Day07.parse("Oronris,\n\nr > a").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 9: expected a name");
+    }
+
+    #[test]
+    fn letters_without_rules_end_a_name() {
+        let char_map = HashMap::from([('a', vec!['b'])]);
+        assert!(is_valid(&char_map, "ab"));
+        assert!(!is_valid(&char_map, "abc"));
+        assert_eq!(sum_indices_valid_names(&char_map, &["abc".to_string(), "ab".to_string()]), 2);
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day07.part1(&Day07.parse(EXAMPLE_1).unwrap()), "Oroneth");
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day07.part2(&Day07.parse(EXAMPLE_2).unwrap()), "23");
+    }
+
+    #[test]
+    fn part_3_example() {
+        let input = "Xaryt\n\nX > a,o\na > r,t\nr > y,e,a\nh > a,e,v\nt > h\nv > e\ny > p,t";
+        assert_eq!(Day07.part3(&Day07.parse(input).unwrap()), "25");
+    }
+
+    #[test]
+    fn counts_names_between_7_and_11_letters() {
+        // Every letter can only be followed by itself, so there is exactly one name per length
+        let char_map = HashMap::from([('a', vec!['a'])]);
+        let mut memo = Memo::new();
+        assert_eq!(get_number_unique_names(&char_map, &mut memo, 'a', 1), 5);
+        assert_eq!(get_number_unique_names(&char_map, &mut memo, 'a', 7), 5);
+        assert_eq!(get_number_unique_names(&char_map, &mut memo, 'a', 11), 1);
+        // The counts of the longer names were already known from the first call
+        assert_eq!(memo.stats().misses, 11);
+    }
+}
@@ -0,0 +1,130 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span, numbers};
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    type Input = Vec<isize>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, ParseError> {
+        numbers(Span::new(input))
+    }
+
+    fn part1(&self, instructions: &Vec<isize>) -> usize {
+        part_1(instructions)
+    }
+
+    fn part2(&self, instructions: &Vec<isize>) -> usize {
+        part_2(instructions)
+    }
+
+    fn part3(&self, instructions: &Vec<isize>) -> usize {
+        part_3(instructions)
+    }
+}
+
+fn part_1(instructions: &[isize]) -> usize {
+    count_center_crossings(instructions, 32)
+}
+
+fn count_center_crossings(instructions: &[isize], num_nails: isize) -> usize {
+    // A distance difference of half the number of nails crosses the center
+    instructions.windows(2)
+        .map(|w| if (w[0] - w[1]).abs() == num_nails / 2 {1} else {0})
+        .sum()
+}
+
+fn part_2(instructions: &[isize]) -> usize {
+    let lines: Vec<_> = instructions.windows(2).collect();
+    let mut sum = 0;
+    for i in 1..lines.len() {
+        for j in 0..i {
+            sum += check_for_knot(lines[i], lines[j], false)
+        }
+    }
+
+    sum
+}
+
+fn part_3(instructions: &[isize]) -> usize {
+    find_best_cut(instructions, 256)
+}
+
+fn find_best_cut(instructions: &[isize], num_nails: isize) -> usize {
+    let lines: Vec<_> = instructions.windows(2).collect();
+    let possible_cuts = (1..num_nails + 1)
+        .flat_map(|start| (start + 1..num_nails + 1).map(move |end| [start, end])).collect::<Vec<_>>();
+    let mut max = 0;
+    for cut in possible_cuts {
+        let sum: usize = lines.iter()
+            .map(|line| check_for_knot(line, &cut, true))
+            .sum();
+        if sum > max {
+            max = sum;
+        }
+    }
+
+    max
+}
+
+fn check_for_knot(line_0: &[isize], line_1: &[isize], include_identical: bool) -> usize {
+    // First ensure the lines are always running from the lowest to the highest number
+    let line_0 = (
+        std::cmp::min(line_0[0], line_0[1]),
+        std::cmp::max(line_0[0], line_0[1])
+    );
+    let line_1 = (
+        std::cmp::min(line_1[0], line_1[1]),
+        std::cmp::max(line_1[0], line_1[1])
+    );
+
+    if include_identical && line_0.0 == line_1.0 && line_0.1 == line_1.1 {
+        return 1;
+    }
+
+    if line_0.0 < line_1.0 && line_0.1 > line_1.0 && line_0.1 < line_1.1 {
+        return 1;
+    }
+
+    if line_1.0 < line_0.0 && line_1.1 > line_0.0 && line_1.1 < line_0.1 {
+        return 1;
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let instructions = Day08.parse("1,5,2,6,8,4,1,7,3").unwrap();
+        assert_eq!(count_center_crossings(&instructions, 8), 4);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let instructions = Day08.parse("1,5,2,6,8,4,1,7,3,5,7,8,2").unwrap();
+        assert_eq!(Day08.part2(&instructions), 21);
+    }
+
+    #[test]
+    fn part_3_example() {
+        let instructions = Day08.parse("1,5,2,6,8,4,1,7,3,6").unwrap();
+        assert_eq!(find_best_cut(&instructions, 8), 7);
+    }
+
+    #[test]
+    fn knots_only_cross_strictly() {
+        assert_eq!(check_for_knot(&[1, 5], &[2, 6], false), 1);
+        assert_eq!(check_for_knot(&[6, 2], &[5, 1], false), 1);
+        // Threads sharing a nail do not cross
+        assert_eq!(check_for_knot(&[1, 5], &[5, 7], false), 0);
+        assert_eq!(check_for_knot(&[1, 5], &[2, 4], false), 0);
+        assert_eq!(check_for_knot(&[1, 5], &[5, 1], false), 0);
+        assert_eq!(check_for_knot(&[1, 5], &[5, 1], true), 1);
+    }
+}
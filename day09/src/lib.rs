@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::izip;
+
+use utils::Solver;
+use utils::parse::{ParseError, Span, key_value};
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<(usize, Vec<char>)>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(usize, Vec<char>)>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, sequences: &Vec<(usize, Vec<char>)>) -> usize {
+        part_1(sequences)
+    }
+
+    fn part2(&self, sequences: &Vec<(usize, Vec<char>)>) -> usize {
+        part_2(sequences)
+    }
+
+    fn part3(&self, sequences: &Vec<(usize, Vec<char>)>) -> usize {
+        part_3(sequences)
+    }
+}
+
+fn part_1(sequences: &[(usize, Vec<char>)]) -> usize {
+    get_children_similarities(sequences)
+}
+
+fn part_2(sequences: &[(usize, Vec<char>)]) -> usize {
+    get_children_similarities(sequences)
+}
+
+fn part_3(sequences: &[(usize, Vec<char>)]) -> usize {
+    let family_members = find_biggest_family(sequences);
+    family_members.iter().sum()
+}
+
+fn compare_sequences(sequence_0: &[char], sequence_1: &[char]) -> usize {
+    sequence_0.iter()
+        .zip(sequence_1.iter())
+        .filter(|(a, b)| a == b)
+        .count()
+}
+
+fn get_children_similarities(sequences: &[(usize, Vec<char>)]) -> usize {
+    let mut children: HashMap<usize, usize> = HashMap::new();
+    for i in 0..sequences.len() {
+        for j in 0..sequences.len() {
+            for k in j + 1..sequences.len() {
+                if i == j || i == k || children.contains_key(&j) || children.contains_key(&k) {
+                    continue
+                }
+                if is_child_of(&sequences[i].1, &sequences[j].1, &sequences[k].1) {
+                    let score = compare_sequences(&sequences[i].1, &sequences[j].1) *
+                        compare_sequences(&sequences[i].1, &sequences[k].1);
+                    children.insert(i, score);
+                }
+            }
+        }
+    }
+    children.values().sum()
+}
+
+fn is_child_of(child: &[char], parent_0: &[char], parent_1: &[char]) -> bool {
+    izip!(child, parent_0, parent_1)
+        .all(|(ch, p0, p1)| ch == p0 || ch == p1)
+}
+
+fn find_biggest_family(sequences: &[(usize, Vec<char>)]) -> Vec<usize> {
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for i in 0..sequences.len() {
+        for j in 0..sequences.len() {
+            for k in j + 1..sequences.len() {
+                if i == j || i == k {
+                    continue
+                }
+                if is_child_of(&sequences[i].1, &sequences[j].1, &sequences[k].1) {
+                    children.insert(i + 1, vec![sequences[j].0, sequences[k].0]);
+                }
+            }
+        }
+    }
+    let mut lineages = children.keys()
+        .map(|child| (*child, find_lineage(&children, *child, HashSet::new())))
+        .collect::<Vec<(usize, HashSet<usize>)>>();
+
+    // Updating one child's (grand-)parents can also lead to new values for already processed
+    // children. Ensure that there is no non-updated value left
+    let mut new_lineages = vec![];
+    while new_lineages != lineages {
+        new_lineages = lineages.clone();
+        lineages = lineages.iter()
+            .map(|(child, lineage)| {
+                let mut lineage_set = lineages.iter()
+                    .filter(|(_, lineage_2)| lineage.intersection(lineage_2).count() > 0)
+                    .flat_map(|(_, lineage_2)| lineage_2.iter().copied().collect::<HashSet<usize>>())
+                    .collect::<HashSet<usize>>();
+                lineage_set.extend(lineage);
+                (*child, lineage_set)
+            })
+            .collect::<Vec<(usize, HashSet<usize>)>>();
+    }
+
+    lineages.iter()
+        .max_by_key(|(_, lineage)| lineage.len())
+        .map(|(_, lineage)| lineage.iter().copied().collect::<Vec<usize>>())
+        .unwrap()
+}
+
+fn find_lineage(children: &HashMap<usize, Vec<usize>>, child: usize, mut lineage: HashSet<usize>) -> HashSet<usize> {
+    lineage.insert(child);
+    if !children.contains_key(&child) {
+        return lineage;
+    }
+    let new: HashSet<usize> = children.get(&child)
+        .unwrap()
+        .iter()
+        .flat_map(|parent| find_lineage(children, *parent, lineage.clone()))
+        .collect();
+    lineage.extend(&new);
+    lineage
+}
+
+fn parse(input: &str) -> Result<Vec<(usize, Vec<char>)>, ParseError> {
+    Span::lines(input)
+        .map(|line| key_value(line, Span::number, |sequence| Ok(sequence.text.chars().collect())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1:CAAGCGCTAAGTTCGCTGGATGTGTGCCCGCG
+2:CTTGAATTGGGCCGTTTACCTGGTTTAACCAT
+3:CTAGCGCTGAGCTGGCTGCCTGGTTGACCGCG";
+
+    const EXAMPLE_2: &str = "\
+1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day09.part1(&Day09.parse(EXAMPLE_1).unwrap()), 414);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day09.part2(&Day09.parse(EXAMPLE_2).unwrap()), 1245);
+    }
+
+    #[test]
+    fn part_3_example() {
+        assert_eq!(Day09.part3(&Day09.parse(EXAMPLE_2).unwrap()), 12);
+    }
+
+    #[test]
+    fn child_takes_every_symbol_from_a_parent() {
+        let sequences = Day09.parse(EXAMPLE_1).unwrap();
+        assert!(is_child_of(&sequences[2].1, &sequences[0].1, &sequences[1].1));
+        assert!(!is_child_of(&sequences[0].1, &sequences[1].1, &sequences[2].1));
+        assert_eq!(compare_sequences(&sequences[2].1, &sequences[0].1), 23);
+    }
+}
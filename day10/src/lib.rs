@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+
+use utils::grid::Grid;
+use utils::memo::Memo;
+use utils::parse::{ParseError, Span};
+use utils::Solver;
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ChessBoard {
+    sheep: Vec<(isize, isize)>,
+    dragon: (isize, isize),
+    // Marks the hideouts where sheep cannot be eaten
+    safe: Grid<bool>,
+}
+
+impl ChessBoard {
+    fn from_text(input: &str) -> Result<ChessBoard, ParseError> {
+        let tiles = Grid::try_parse(input, |tile| match tile.text {
+            "." | "#" | "S" | "D" => tile.char(),
+            _ => Err(tile.error(format!("expected '.', '#', 'S' or 'D', found '{}'", tile.text))),
+        })?;
+        if tiles.is_empty() {
+            return Err(Span::end(input).error("expected a board"));
+        }
+
+        let mut sheep: Vec<(isize, isize)> = Vec::new();
+        let mut dragon: (isize, isize) = (0, 0);
+
+        tiles.iter()
+            .for_each(|((row, col), &c)| {
+                if c == 'S' {
+                    sheep.push((row as isize, col as isize));
+                }
+                else if c == 'D' {
+                    dragon = (row as isize, col as isize);
+                }
+            });
+
+        let mut board = ChessBoard {
+            sheep,
+            dragon,
+            safe: tiles.map(|&c| c == '#'),
+        };
+        board.sort_sheep();
+        Ok(board)
+    }
+
+    fn is_safe(&self, pos: &(isize, isize)) -> bool {
+        self.safe.checked_position(pos.0, pos.1)
+            .is_some_and(|pos| self.safe[pos])
+    }
+
+    fn is_valid_move(&self, end: &(isize, isize)) -> bool {
+        self.safe.in_bounds(end.0, end.1)
+    }
+
+    fn sort_sheep(&mut self) {
+        self.sheep.sort_unstable();
+    }
+
+    fn move_all_sheep(&mut self) {
+        self.sheep = self.sheep.iter()
+            .map(|(row, col)| (*row + 1, *col))
+            .filter(|pos| self.is_valid_move(pos))
+            .collect();
+    }
+
+    fn move_one_sheep(&mut self, old_pos: (isize, isize)) {
+        self.sheep = self.sheep.iter_mut()
+            .map(|pos| if pos == &old_pos {(pos.0 + 1, pos.1)} else {*pos})
+            .collect();
+        self.sort_sheep();
+    }
+
+    fn get_possible_sheep_moves(&self) -> Vec<((isize, isize),(isize, isize))> {
+        let potential_moves: Vec<((isize, isize),(isize, isize))> = self.sheep.iter()
+            .map(|(row, col)| ((*row, *col), (*row + 1, *col)))
+            .filter(|(_, pos)| pos != &self.dragon || self.is_safe(pos))
+            .collect();
+        potential_moves
+    }
+
+    fn sheep_escapes(&self, sheep_pos: &(isize, isize)) -> bool {
+        sheep_pos.0 >= self.safe.height() as isize
+    }
+
+    fn remove_sheep(&mut self, visited: &[(isize, isize)]) -> usize {
+        let before = self.sheep.len();
+        self.sheep.retain(|&pos| !visited.contains(&pos));
+        self.sort_sheep();
+        before - self.sheep.len()
+    }
+
+    fn remove_sheep_current_dragon(&mut self) {
+        let mut sheep = std::mem::take(&mut self.sheep);
+        sheep.retain(|pos| self.dragon != *pos || self.is_safe(pos));
+        self.sheep = sheep;
+        self.sort_sheep();
+    }
+
+    fn move_sheep_get_boards(&self) -> Vec<ChessBoard> {
+        // Return a copy of the boards after each possible sheep move on the current board
+        // Filter out the boards where the sheep escapes, since this is a failure of the dragon
+        let sheep_moves = self.get_possible_sheep_moves();
+
+        if sheep_moves.is_empty() {
+            return vec![self.clone()];
+        }
+
+        sheep_moves.into_iter()
+            .filter(|(_, new)| !self.sheep_escapes(new))
+            .map(|(old, _)| {
+                let mut board = self.clone();
+                board.move_one_sheep(old);
+                board
+            })
+            .collect::<Vec<ChessBoard>>()
+    }
+
+    #[allow(dead_code)]
+    fn print_board(&self) {
+        let mut board = self.safe.map(|&safe| if safe { '#' } else { '.' });
+        for pos in &self.sheep {
+            if let Some(pos) = board.checked_position(pos.0, pos.1) {
+                board[pos] = 'S';
+            }
+        }
+        if let Some(pos) = board.checked_position(self.dragon.0, self.dragon.1) {
+            board[pos] = 'D';
+        }
+        println!("{}", board);
+        println!("\n\n");
+    }
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Input = ChessBoard;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<ChessBoard, ParseError> {
+        ChessBoard::from_text(input)
+    }
+
+    fn part1(&self, board: &ChessBoard) -> usize {
+        part_1(board)
+    }
+
+    fn part2(&self, board: &ChessBoard) -> usize {
+        part_2(board.clone())
+    }
+
+    fn part3(&self, board: &ChessBoard) -> usize {
+        part_3(board.clone())
+    }
+}
+
+fn part_1(board: &ChessBoard) -> usize {
+    count_reachable_sheep(board, 4)
+}
+
+fn count_reachable_sheep(board: &ChessBoard, moves: isize) -> usize {
+    let visited = move_dragon_max_times(board.dragon, 0, moves, &board.safe);
+    get_killed_sheep(&visited, board).len()
+}
+
+fn part_2(board: ChessBoard) -> usize {
+    count_eaten_sheep(board, 20)
+}
+
+fn count_eaten_sheep(mut board: ChessBoard, rounds: usize) -> usize {
+    let mut total_sheep = 0;
+
+    let mut dragon_pos: Vec<(isize, isize)> = vec![board.dragon];
+
+    for _ in 0..rounds {
+        let mut visited: HashSet<(isize, isize)> = HashSet::new();
+        dragon_pos.iter()
+            .map(|&pos| move_dragon_max_times(pos, 0, 1, &board.safe))
+            .for_each(|set| visited.extend(&set));
+        // First check which sheep are at the new dragon positions
+        let killed_sheep = get_killed_sheep(&visited, &board);
+        total_sheep += board.remove_sheep(&killed_sheep);
+
+        // Check which sheep walk into dragon positions
+       board.move_all_sheep();
+        let killed_sheep = get_killed_sheep(&visited, &board);
+        total_sheep += board.remove_sheep(&killed_sheep);
+
+        dragon_pos = visited.into_iter().collect();
+    }
+    total_sheep
+}
+
+fn part_3(board: ChessBoard) -> usize {
+    let mut memo = Memo::new();
+    dfs_memo(board, &mut memo, false)
+}
+
+
+fn dfs_memo(board: ChessBoard, memo: &mut Memo<(ChessBoard, bool), usize>, dragon_turn: bool) -> usize {
+    memo.get_or_insert_with((board.clone(), dragon_turn), |memo| {
+        if board.sheep.is_empty() {
+            return 1;
+        }
+
+        let mut total = 0;
+
+        if dragon_turn {
+            let dragon_boards = move_dragon_get_boards(board);
+
+            for mut dragon_board in dragon_boards {
+                dragon_board.remove_sheep_current_dragon();
+                total += dfs_memo(dragon_board, memo, false);
+            }
+        }
+        else {
+            let sheep_move_boards = board.move_sheep_get_boards();
+
+            if sheep_move_boards.is_empty() {
+                return 0;
+            }
+
+            for sheep_board in sheep_move_boards {
+                total += dfs_memo(sheep_board, memo, true);
+            }
+        }
+        total
+    })
+}
+
+fn move_dragon_get_boards(board: ChessBoard) -> Vec<ChessBoard> {
+    // Return a copy of the boards after each possible dragon move on the current board
+    let row = board.dragon.0;
+    let col = board.dragon.1;
+
+    let mut new_points = Vec::from([(row + 2, col + 1), (row + 2, col - 1), (row + 1, col + 2), (row + 1, col - 2),
+        (row - 1, col + 2), (row - 1, col - 2), (row - 2, col + 1), (row - 2, col - 1)]);
+    new_points = new_points.into_iter()
+        .filter(|point| board.is_valid_move(point))
+        .collect::<Vec<(isize, isize)>>();
+
+    new_points.into_iter()
+        .map(|new| {
+            let mut board_clone = board.clone();
+            board_clone.dragon = new;
+            board_clone
+        })
+        .collect::<Vec<ChessBoard>>()
+}
+
+fn get_killed_sheep(visited: &HashSet<(isize, isize)>, board: &ChessBoard) -> Vec<(isize, isize)> {
+    board.sheep.iter()
+        .filter(|&pos| visited.contains(pos) && !board.is_safe(pos))
+        .cloned()
+        .collect()
+}
+
+fn move_dragon_max_times(dragon: (isize, isize), n: isize, max: isize, board: &Grid<bool>) -> HashSet<(isize, isize)> {
+    if n == max {
+        return HashSet::new();
+    }
+
+    let row = dragon.0;
+    let col = dragon.1;
+
+    let mut new_points = HashSet::from([(row + 2, col + 1), (row + 2, col - 1), (row + 1, col + 2), (row + 1, col - 2),
+                          (row - 1, col + 2), (row - 1, col - 2), (row - 2, col + 1), (row - 2, col - 1)]);
+    new_points = new_points.into_iter()
+        .filter(|&point| board.in_bounds(point.0, point.1))
+        .collect::<HashSet<(isize, isize)>>();
+
+    new_points.extend(new_points.iter()
+        .flat_map(|point| move_dragon_max_times(*point, n + 1, max, board))
+        .collect::<HashSet<(isize, isize)>>());
+    new_points
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+...SSS.......
+.S......S.SS.
+..S....S...S.
+..........SS.
+..SSSS...S...
+.....SS..S..S
+SS....D.S....
+S.S..S..S....
+....S.......S
+.SSS..SS.....
+.........S...
+.......S....S
+SS.....S..S..";
+
+    const EXAMPLE_2: &str = "\
+...SSS##.....
+.S#.##..S#SS.
+..S.##.S#..S.
+.#..#S##..SS.
+..SSSS.#.S.#.
+.##..SS.#S.#S
+SS##.#D.S.#..
+S.S..S..S###.
+.##.S#.#....S
+.SSS.#SS..##.
+..#.##...S##.
+.#...#.S#...S
+SS...#.S.#S..";
+
+    #[test]
+    fn parses_board() {
+        let board = Day10.parse("SS.\n.#D").unwrap();
+        assert_eq!(board.sheep, vec![(0, 0), (0, 1)]);
+        assert_eq!(board.dragon, (1, 2));
+        assert!(board.is_safe(&(1, 1)));
+        assert!(!board.is_safe(&(0, 2)));
+    }
+
+    #[test]
+    fn malformed_boards_are_reported() {
+        let error = Day10.parse("SS.\n.#").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row of 3 cells, found 2");
+        let error = Day10.parse("SX.").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 2: expected '.', '#', 'S' or 'D', found 'X'");
+        assert_eq!(Day10.parse("").unwrap_err().message, "expected a board");
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(count_reachable_sheep(&Day10.parse(EXAMPLE_1).unwrap(), 3), 27);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(count_eaten_sheep(Day10.parse(EXAMPLE_2).unwrap(), 3), 27);
+    }
+
+    #[test]
+    fn part_3_examples() {
+        assert_eq!(Day10.part3(&Day10.parse("SSS\n..#\n#.#\n#D.").unwrap()), 15);
+        assert_eq!(Day10.part3(&Day10.parse("SSS\n..#\n..#\n.##\n.D#").unwrap()), 8);
+        assert_eq!(Day10.part3(&Day10.parse("..S..\n.....\n..#..\n.....\n..D..").unwrap()), 44);
+        assert_eq!(Day10.part3(&Day10.parse(".SS.S\n#...#\n...#.\n##..#\n.####\n##D.#").unwrap()), 4406);
+        assert_eq!(Day10.part3(&Day10.parse("SSS.S\n.....\n#.#.#\n.#.#.\n#.D.#").unwrap()), 13033988838);
+    }
+}
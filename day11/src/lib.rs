@@ -0,0 +1,206 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span};
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_columns(input)
+    }
+
+    fn part1(&self, columns: &Vec<usize>) -> usize {
+        part_1(columns.clone())
+    }
+
+    fn part2(&self, columns: &Vec<usize>) -> usize {
+        part_2(columns.clone())
+    }
+
+    fn part3(&self, columns: &Vec<usize>) -> usize {
+        part_3(columns.clone())
+    }
+}
+
+fn part_1(mut columns: Vec<usize>) -> usize {
+
+    let mut checksum = 0;
+    let mut changed = true;
+
+    let mut round = 0;
+
+    // Phase 1
+    while changed {
+        (columns, changed) = phase_1(columns);
+        if changed {
+            round += 1;
+        }
+
+        if round == 10 {
+            checksum = get_checksum(&columns);
+            break;
+        }
+    }
+
+    // Phase 2
+    changed = true;
+
+    while changed {
+        (columns, changed) = phase_2(columns);
+        if changed {
+            round += 1;
+        }
+
+        if round == 10 {
+            checksum = get_checksum(&columns);
+            break;
+        }
+    }
+    checksum
+}
+
+fn part_2(mut columns: Vec<usize>) -> usize {
+
+    let mut changed = true;
+    let mut round = 0;
+
+    // Phase 1
+    while changed {
+        (columns, changed) = phase_1(columns);
+        if changed {
+            round += 1;
+        }
+    }
+
+    round += phase_2_rapid(columns);
+
+    round
+}
+
+fn part_3(mut columns: Vec<usize>) -> usize {
+
+    let mut changed = true;
+    let mut round = 0;
+
+    // Phase 1
+    while changed {
+        (columns, changed) = phase_1(columns);
+        if changed {
+            round += 1;
+        }
+    }
+    round += phase_2_rapid(columns);
+    round
+}
+
+fn parse_columns(input: &str) -> Result<Vec<usize>, ParseError> {
+    Span::lines(input)
+        .map(Span::number::<usize>)
+        .collect()
+}
+
+fn phase_1(mut columns: Vec<usize>) -> (Vec<usize>, bool) {
+    let mut changed = false;
+
+    for i in 0..columns.len() - 1 {
+        if columns[i + 1] < columns[i] {
+            changed = true;
+            columns[i + 1] += 1;
+            columns[i] -= 1;
+        }
+    }
+    (columns, changed)
+}
+
+fn phase_2(mut columns: Vec<usize>) -> (Vec<usize>, bool) {
+    let mut changed = false;
+
+    for i in 0..columns.len() - 1 {
+        if columns[i + 1] > columns[i] {
+            changed = true;
+            columns[i + 1] -= 1;
+            columns[i] += 1;
+        }
+    }
+    (columns, changed)
+}
+
+fn phase_2_rapid(columns: Vec<usize>) -> usize {
+    // Checkout how many ducks are missing below the expected number of ducks per column
+    let ducks_per_column: usize = columns.iter().sum::<usize>() / columns.len();
+
+    columns.iter()
+        .filter(|&&ducks| ducks < ducks_per_column)
+        .map(|&ducks| ducks_per_column - ducks)
+        .sum()
+}
+
+fn get_checksum(column: &[usize]) -> usize {
+    column.iter()
+        .enumerate()
+        .map(|(idx, val)| (idx + 1) * val)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "9\n1\n1\n4\n9\n6";
+    const EXAMPLE_2: &str = "805\n706\n179\n48\n158\n150\n232\n885\n598\n524\n423";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day11.part1(&Day11.parse(EXAMPLE_1).unwrap()), 109);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        assert_eq!(Day11.part2(&Day11.parse(EXAMPLE_1).unwrap()), 11);
+        assert_eq!(Day11.part2(&Day11.parse(EXAMPLE_2).unwrap()), 1579);
+    }
+
+    // The example of part 3 was not kept with the quest. Its columns come sorted, so phase 1 does
+    // nothing and every round of phase 2 moves one duck up to a column below the average.
+    #[test]
+    fn part_3_sorted_columns() {
+        assert_eq!(Day11.part3(&Day11.parse("1\n2\n5\n5\n7").unwrap()), 5);
+    }
+
+    #[test]
+    fn phase_1_moves_ducks_to_the_right() {
+        let (columns, changed) = phase_1(vec![9, 1, 1, 4, 9, 6]);
+        assert_eq!(columns, vec![8, 1, 2, 4, 8, 7]);
+        assert!(changed);
+
+        let (columns, changed) = phase_1(vec![1, 2, 3]);
+        assert_eq!(columns, vec![1, 2, 3]);
+        assert!(!changed);
+    }
+
+    #[test]
+    fn phase_2_rapid_matches_simulation() {
+        let mut columns = vec![1, 2, 5, 5, 7];
+        let rapid = phase_2_rapid(columns.clone());
+
+        let mut rounds = 0;
+        let mut changed = true;
+        while changed {
+            (columns, changed) = phase_2(columns);
+            if changed {
+                rounds += 1;
+            }
+        }
+        assert_eq!(rapid, rounds);
+        assert_eq!(columns, vec![4, 4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn malformed_columns_are_reported() {
+        let error = Day11.parse("9\n1\n-4").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a number, found \"-4\"");
+    }
+}
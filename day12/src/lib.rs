@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::grid::{Grid, Position};
+use utils::parse::{ParseError, Span};
+use utils::{Solver, search};
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<usize>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<usize>) -> usize {
+        part_1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<usize>) -> usize {
+        part_2(grid)
+    }
+
+    fn part3(&self, grid: &Grid<usize>) -> usize {
+        part_3(grid)
+    }
+}
+
+fn part_1(input: &Grid<usize>) -> usize {
+    let barrels = dfs_connected_barrels(input, vec![(0, 0)]);
+    barrels.len()
+}
+
+fn part_2(input: &Grid<usize>) -> usize {
+    let max_row = input.height() - 1;
+    let max_col = input.width() - 1;
+    let barrels = dfs_connected_barrels(input, vec![(0, 0), (max_row, max_col)]);
+    barrels.len()
+}
+
+fn part_3(input: &Grid<usize>) -> usize {
+    let mut exclude : HashSet<Position> = HashSet::new();
+    let mut starts: Vec<Position> = Vec::new();
+
+    for _ in 0..3 {
+        let result = greedy_check(input, &exclude);
+        starts.push(result.0);
+        exclude.extend(result.1);
+    }
+    let barrels = dfs_connected_barrels(input, starts);
+    barrels.len()
+}
+
+fn greedy_check(grid: &Grid<usize>, exclude: &HashSet<Position>) -> (Position, HashSet<Position>) {
+    let mut barrels_per_point : HashMap<Position, usize> = HashMap::new();
+    let mut visited : HashSet<Position> = HashSet::new();
+
+    // Start from points with highest value, slightly faster than naively starting at (0, 0)
+    let mut points = grid.iter()
+        .map(|(pos, &value)| (pos, value))
+        .collect::<Vec<(Position, usize)>>();
+    points.sort_by_key(|(_, value)| -(*value as isize));
+
+    for point in points {
+        let point = point.0;
+        if visited.contains(&point) || exclude.contains(&point) {
+            continue;
+        }
+        let barrels = dfs_connected_barrels(grid, vec![point]);
+        let new: HashSet<_> = barrels.difference(exclude).cloned().collect();
+        visited.extend(&barrels);
+        barrels_per_point.insert(point, new.len());
+    }
+
+    let max_key_value = barrels_per_point.into_iter()
+        .max_by_key(|(_, value)| *value)
+        .unwrap();
+
+    let max_point = max_key_value.0;
+    let visited = dfs_connected_barrels(grid, vec![max_point]);
+    (max_point, visited)
+}
+
+fn dfs_connected_barrels(grid: &Grid<usize>, start: Vec<Position>) -> HashSet<Position> {
+    let neighbours = |&current: &Position| {
+        grid.neighbours4(current)
+            .filter(move |new| grid[current] >= grid[*new])
+    };
+
+    search::dfs(start, neighbours, |_| false)
+        .visited()
+        .copied()
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::try_parse(input, Span::number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let grid = Day12.parse("989601\n857782\n746543\n766789").unwrap();
+        assert_eq!(Day12.part1(&grid), 16);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let grid = Day12.parse("\
+9589233445
+9679121695
+8469121876
+8352919876
+7342914327
+7234193437
+6789193538
+6781219648
+5691219769
+5443329859").unwrap();
+        assert_eq!(Day12.part2(&grid), 58);
+    }
+
+    #[test]
+    fn part_3_example() {
+        let grid = Day12.parse("5411\n3362\n5235\n3112").unwrap();
+        assert_eq!(Day12.part3(&grid), 14);
+    }
+
+    #[test]
+    fn fire_spreads_to_lower_or_equal_barrels() {
+        let grid = Day12.parse("321\n123").unwrap();
+        let barrels = dfs_connected_barrels(&grid, vec![(0, 0)]);
+        assert_eq!(barrels, HashSet::from([(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)]));
+    }
+
+    #[test]
+    fn malformed_grids_are_reported() {
+        let error = Day12.parse("989\n8x7").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected a number, found \"x\"");
+        let error = Day12.parse("989\n87").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row of 3 cells, found 2");
+    }
+}
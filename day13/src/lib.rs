@@ -0,0 +1,95 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span, range};
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, clock: &Vec<usize>) -> usize {
+        part_1(clock)
+    }
+
+    fn part2(&self, clock: &Vec<usize>) -> usize {
+        part_2(clock)
+    }
+
+    fn part3(&self, clock: &Vec<usize>) -> usize {
+        part_3(clock)
+    }
+}
+
+fn part_1(clock: &[usize]) -> usize {
+    clock[2025 % clock.len()]
+}
+
+fn part_2(clock: &[usize]) -> usize {
+    clock[20252025 % clock.len()]
+}
+
+fn part_3(clock: &[usize]) -> usize {
+    clock[202520252025 % clock.len()]
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut first: Vec<usize> = Vec::new();
+    let mut second: Vec<usize> = Vec::new();
+
+    for (i, line) in Span::lines(input).enumerate() {
+        // A single number is a range of length one
+        let (lo, hi) = range::<usize>(line)?;
+        let mut vec = (lo..hi + 1).collect::<Vec<usize>>();
+        if i % 2 == 0 {
+            first.append(&mut vec);
+        }
+        else {
+            second.append(&mut vec);
+        }
+    }
+
+    let mut total = vec![1];
+
+
+    second.reverse();
+    total.append(&mut first);
+    total.append(&mut second);
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day13.part1(&Day13.parse("72\n58\n47\n61\n67").unwrap()), 67);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day13.part2(&Day13.parse("10-15\n12-13\n20-21\n19-23\n30-37").unwrap()), 30);
+    }
+
+    // The example of part 3 was not kept with the quest, and the dial of part 2 cannot stand in
+    // for it: with 24 numbers, 20252025 and 202520252025 turns stop at the same place. This dial of
+    // 11 numbers stops at a different place for each part.
+    #[test]
+    fn part_3_turns_further_than_part_2() {
+        let clock = Day13.parse("10-15\n20-23").unwrap();
+        assert_eq!(clock, vec![1, 10, 11, 12, 13, 14, 15, 23, 22, 21, 20]);
+        assert_eq!(Day13.part1(&clock), 10);
+        assert_eq!(Day13.part2(&clock), 11);
+        assert_eq!(Day13.part3(&clock), 12);
+    }
+
+    #[test]
+    fn parse_places_odd_lines_counterclockwise() {
+        assert_eq!(parse("2\n3\n4-5"), Ok(vec![1, 2, 4, 5, 3]));
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use rayon::prelude::*;
+
+use utils::cycle::find_cycle;
+use utils::grid::Grid;
+use utils::parse::{ParseError, Span};
+use utils::Solver;
+
+#[derive(Debug, Clone)]
+pub struct SymbolGrid {
+    tiles: Grid<bool>,
+    diagonals: Vec<Vec<usize>>,
+}
+
+impl SymbolGrid {
+    fn new_inactive(width: usize, height: usize) -> SymbolGrid {
+        SymbolGrid::from_tiles(Grid::filled(width, height, false))
+    }
+
+    fn from_text(input: &str) -> Result<SymbolGrid, ParseError> {
+        let tiles = Grid::try_parse(input, |tile| match tile.text {
+            "#" => Ok(true),
+            "." => Ok(false),
+            _ => Err(tile.error(format!("expected '#' or '.', found '{}'", tile.text))),
+        })?;
+        if tiles.is_empty() {
+            return Err(Span::end(input).error("expected a pattern"));
+        }
+        Ok(SymbolGrid::from_tiles(tiles))
+    }
+
+    fn from_tiles(tiles: Grid<bool>) -> SymbolGrid {
+        let diagonals = SymbolGrid::get_diagonals(&tiles);
+        SymbolGrid { tiles, diagonals }
+    }
+
+    fn add_round(&mut self) -> usize {
+        self.tiles = self.next_round(&self.tiles);
+        count_active(&self.tiles)
+    }
+
+    fn next_round(&self, tiles: &Grid<bool>) -> Grid<bool> {
+        // An active tile stays active with an odd number of active diagonals, an inactive tile
+        // becomes active with an even number
+        let cells = (0..tiles.len()).into_par_iter()
+            .map(|idx| {
+                let active_diagonals = self.diagonals[idx].iter()
+                    .filter(|&&diag| tiles[diag])
+                    .count();
+
+                tiles[idx] == (active_diagonals % 2 != 0)
+            })
+            .collect::<Vec<bool>>();
+
+        Grid::new(tiles.width(), tiles.height(), cells)
+    }
+
+    fn active(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.tiles.len()).filter(|&idx| self.tiles[idx])
+    }
+
+    fn inactive(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.tiles.len()).filter(|&idx| !self.tiles[idx])
+    }
+
+    fn get_diagonals(tiles: &Grid<bool>) -> Vec<Vec<usize>> {
+        tiles.positions()
+            .map(|pos| {
+                tiles.diagonal_neighbours(pos)
+                    .map(|neighbour| tiles.index_of(neighbour))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    type Input = SymbolGrid;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<SymbolGrid, ParseError> {
+        SymbolGrid::from_text(input)
+    }
+
+    fn part1(&self, grid: &SymbolGrid) -> usize {
+        part_1(grid.clone())
+    }
+
+    fn part2(&self, grid: &SymbolGrid) -> usize {
+        part_2(grid.clone())
+    }
+
+    fn part3(&self, grid: &SymbolGrid) -> usize {
+        part_3(grid)
+    }
+}
+
+fn part_1(mut grid: SymbolGrid) -> usize {
+    let mut actives = 0;
+    for _ in 0..10 {
+        actives += grid.add_round();
+    }
+    actives
+}
+
+fn part_2(mut grid: SymbolGrid) -> usize {
+    let mut actives = 0;
+    for _ in 0..2025 {
+        actives += grid.add_round();
+    }
+    actives
+}
+
+fn part_3(center_grid: &SymbolGrid) -> usize {
+    let grid = SymbolGrid::new_inactive(34, 34);
+
+    let active_pattern: HashSet<usize> = center_grid.active()
+        .filter_map(|idx| map_to_larger_grid(idx, &grid, center_grid))
+        .collect();
+
+    let inactive_pattern : HashSet<usize> = center_grid.inactive()
+        .filter_map(|idx| map_to_larger_grid(idx, &grid, center_grid))
+        .collect();
+
+    // Only rounds in which the center shows the pattern contribute their active tiles
+    let cycle = find_cycle(grid.tiles.clone(), |tiles| grid.next_round(tiles));
+    cycle.accumulate(1000000000, |tiles| {
+        let matches = active_pattern.iter().all(|&idx| tiles[idx])
+            && inactive_pattern.iter().all(|&idx| !tiles[idx]);
+        if matches { count_active(tiles) } else { 0 }
+    })
+}
+
+fn count_active(tiles: &Grid<bool>) -> usize {
+    tiles.cells().iter().filter(|&&active| active).count()
+}
+
+fn map_to_larger_grid(idx: usize, grid: &SymbolGrid, center_grid: &SymbolGrid) -> Option<usize> {
+    let row_offset = (grid.tiles.height() - center_grid.tiles.height()) / 2;
+    let col_offset = (grid.tiles.width() - center_grid.tiles.width()) / 2;
+    let (original_row, original_col) = center_grid.tiles.position_of(idx)?;
+    Some(grid.tiles.index_of((original_row + row_offset, original_col + col_offset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_round() {
+        // Only the edges of the cross have an even number of active diagonals
+        let mut grid = SymbolGrid::from_text("...\n.#.\n...").unwrap();
+        assert_eq!(grid.add_round(), 4);
+        assert_eq!(grid.active().collect::<Vec<usize>>(), vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn malformed_patterns_are_reported() {
+        let error = Day14.parse("#.\n#.#").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row of 2 cells, found 3");
+        let error = Day14.parse("#.\n#o").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected '#' or '.', found 'o'");
+        assert_eq!(Day14.parse("").unwrap_err().message, "expected a pattern");
+    }
+
+    #[test]
+    fn part_3_example() {
+        let center = "#......#\n..#..#..\n.##..##.\n...##...\n...##...\n.##..##.\n..#..#..\n#......#";
+        assert_eq!(Day14.part3(&Day14.parse(center).unwrap()), 278388552);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use utils::parse::{ParseError, Span, Turn, list, turn};
+use utils::point::{Direction, Point};
+use utils::{Solver, search};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    fn new(start: Point, end: Point) -> Line {
+        Line { start, end }
+    }
+
+    fn contains_point(&self, point: &Point) -> bool {
+        let low = std::cmp::min(self.start, self.end);
+        let high = std::cmp::max(self.start, self.end);
+        point.row >= low.row && point.row <= high.row && point.col >= low.col && point.col <= high.col
+    }
+
+    fn get_max_row(&self) -> isize {
+        std::cmp::max(self.start.row, self.end.row)
+    }
+
+    fn get_min_row(&self) -> isize {
+        std::cmp::min(self.start.row, self.end.row)
+    }
+
+    fn get_max_col(&self) -> isize {
+        std::cmp::max(self.start.col, self.end.col)
+    }
+
+    fn get_min_col(&self) -> isize {
+        std::cmp::min(self.start.col, self.end.col)
+    }
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<(Turn, isize)>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Turn, isize)>, ParseError> {
+        list(Span::new(input), ",", turn)
+    }
+
+    fn part1(&self, instructions: &Vec<(Turn, isize)>) -> usize {
+        part_1(instructions)
+    }
+
+    fn part2(&self, instructions: &Vec<(Turn, isize)>) -> usize {
+        part_2(instructions)
+    }
+
+    fn part3(&self, instructions: &Vec<(Turn, isize)>) -> usize {
+        part_3(instructions)
+    }
+}
+
+fn part_1(instructions: &[(Turn, isize)]) -> usize {
+    calculate_end_point_distance(instructions)
+}
+
+fn part_2(instructions: &[(Turn, isize)]) -> usize {
+    calculate_end_point_distance(instructions)
+}
+
+fn part_3(instructions: &[(Turn, isize)]) -> usize {
+    calculate_end_point_distance(instructions)
+}
+
+fn calculate_end_point_distance(instructions: &[(Turn, isize)]) -> usize {
+    let (wall, end) = parse_wall(HashSet::new(), instructions, Direction::North, Point::ORIGIN);
+    let corners = find_grid_corners(&wall);
+
+    let neighbours = |current: &Point| {
+        current.neighbours4()
+            .into_iter()
+            .filter(|point| *point == end || (!wall_contains_point(&wall, point) && is_valid_point(point, &corners)))
+    };
+
+    search::bfs([Point::ORIGIN], neighbours, |point| *point == end)
+        .goal_distance()
+        .unwrap_or(0)
+}
+
+fn wall_contains_point(wall: &HashSet<Line>, point: &Point) -> bool {
+    wall.iter()
+        .any(|line| line.contains_point(point))
+}
+
+fn is_valid_point(point: &Point, corners: &[Point]) -> bool {
+    point.row >= corners[0].row && point.row <= corners[1].row && point.col >= corners[0].col && point.col <= corners[1].col
+}
+
+fn find_grid_corners(wall: &HashSet<Line>) -> [Point; 2] {
+    let max_row = wall.iter().max_by_key(|a| a.get_max_row()).unwrap().get_max_row() + 2;
+    let min_row = wall.iter().min_by_key(|a| a.get_min_row()).unwrap().get_min_row() - 2;
+    let max_col = wall.iter().max_by_key(|a| a.get_max_col()).unwrap().get_max_col() + 2;
+    let min_col = wall.iter().min_by_key(|a| a.get_min_col()).unwrap().get_min_col() - 2;
+
+    [Point::new(min_row, min_col), Point::new(max_row, max_col)]
+}
+
+fn parse_wall(mut lines: HashSet<Line>, instructions: &[(Turn, isize)], old_direction: Direction, last: Point) -> (HashSet<Line>, Point) {
+    if instructions.is_empty() {
+        return (lines, last);
+    }
+
+    let (turn, distance) = instructions[0];
+
+    let direction = match turn {
+        Turn::Left => old_direction.turn_left(),
+        Turn::Right => old_direction.turn_right(),
+    };
+
+    let end = last + direction.delta() * distance;
+    let line = Line::new(last, end);
+
+    lines.insert(line);
+
+    parse_wall(lines, &instructions[1..], direction, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day15.part1(&Day15.parse("R3,R4,L3,L4,R3,R6,R9").unwrap()), 6);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = "L6,L3,L6,R3,L6,L3,L3,R6,L6,R6,L6,L6,R3,L3,L3,R3,R3,L6,L6,L3";
+        assert_eq!(Day15.part2(&Day15.parse(input).unwrap()), 16);
+    }
+
+    // The example of part 3 was not kept with the quest. Part 3 only has a longer wall, so it is
+    // checked to walk the wall of part 2 the same way.
+    #[test]
+    fn part_3_walks_the_part_2_wall() {
+        let input = "L6,L3,L6,R3,L6,L3,L3,R6,L6,R6,L6,L6,R3,L3,L3,R3,R3,L6,L6,L3";
+        assert_eq!(Day15.part3(&Day15.parse(input).unwrap()), 16);
+    }
+}
@@ -0,0 +1,121 @@
+use utils::Solver;
+use utils::parse::{ParseError, Span, numbers};
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+        numbers(Span::new(input))
+    }
+
+    fn part1(&self, input: &Vec<usize>) -> usize {
+        part_1(input)
+    }
+
+    fn part2(&self, input: &Vec<usize>) -> usize {
+        part_2(input)
+    }
+
+    fn part3(&self, input: &Vec<usize>) -> usize {
+        part_3(input)
+    }
+}
+
+fn part_1(input: &[usize]) -> usize {
+    calculate_blocks_in_wall(input, 90)
+}
+
+fn part_2(input: &[usize]) -> usize {
+    get_spell_from_wall(input, 1, vec![]).iter().product()
+}
+
+fn part_3(input: &[usize]) -> usize {
+    let spells = get_spell_from_wall(input, 1, vec![]);
+    // At least one block of each spell number is used, hence start from the maximum number. The
+    // largest spell alone needs more than all blocks for a wall of blocks + 1 times its length,
+    // and the bound saturates instead of overflowing for a very large spell
+    let blocks = 202520252025000;
+    let largest = spells[spells.len() - 1];
+    get_length_wall(&spells, blocks, largest, (blocks + 1).saturating_mul(largest))
+}
+
+fn calculate_blocks_in_wall(instructions: &[usize], length: usize) -> usize {
+    // The count saturates, so a wall near the saturated bound still needs too many blocks
+    instructions.iter()
+        .fold(0, |count: usize, &num| count.saturating_add(length / num))
+}
+
+fn get_spell_from_wall(wall: &[usize], number: usize, mut spells: Vec<usize>) -> Vec<usize> {
+    let mut new = wall.to_vec();
+    let valid_spell_number = wall.iter()
+        .enumerate()
+        .filter(|(index, _)| (index + 1) % number == 0)
+        .all(|(_, &num)| num > 0);
+
+    if valid_spell_number {
+        new = wall.iter()
+            .enumerate()
+            .map(|(index, &num)| if (index + 1) % number == 0 { num - 1 } else { num } )
+            .collect();
+        spells.push(number);
+    }
+
+    if !new.iter().all(|&num| num == 0) {
+        spells = get_spell_from_wall(&new, number + 1, spells);
+    }
+
+    spells
+}
+
+fn get_length_wall(spells: &[usize], blocks: usize, mut lower: usize, mut upper: usize) -> usize {
+    let mut old = 0;
+    let mut length = 1;
+
+    while length != old {
+        old = length;
+        length = lower + (upper - lower) / 2;
+
+        let needed = calculate_blocks_in_wall(spells, length);
+
+        if needed > blocks {
+            upper = length;
+        }
+
+        else if needed == blocks {
+            return length;
+        }
+
+        else {
+            lower = length;
+        }
+    }
+    
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALL: &str = "1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day16.part1(&Day16.parse("1,2,3,5,9").unwrap()), 193);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(get_spell_from_wall(&Day16.parse(WALL).unwrap(), 1, vec![]), vec![1, 2, 3, 5, 9]);
+        assert_eq!(Day16.part2(&Day16.parse(WALL).unwrap()), 270);
+    }
+
+    #[test]
+    fn part_3_example() {
+        assert_eq!(Day16.part3(&Day16.parse(WALL).unwrap()), 94439495762954);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use utils::grid::{Grid, Position};
+use utils::parse::{ParseError, Span};
+use utils::point::Point;
+use utils::{Solver, search};
+
+#[derive(Debug)]
+pub struct Terrain {
+    cells: Grid<usize>,
+    volcano: Position,
+    // Not used yet, but needed for part 3
+    #[allow(dead_code)]
+    start: Option<Position>,
+}
+
+impl Terrain {
+    fn from_text(input: &str) -> Result<Terrain, ParseError> {
+        let cells = Grid::try_parse(input, |cell| match cell.text {
+            "@" | "S" => Ok(0),
+            _ => cell.number(),
+        })?;
+        let tiles = Grid::try_parse(input, Span::char)?;
+
+        let volcano = tiles.find(|&c| c == '@')
+            .ok_or_else(|| Span::end(input).error("expected a volcano @"))?;
+        let start = tiles.find(|&c| c == 'S');
+
+        Ok(Terrain { cells, volcano, start })
+    }
+
+    fn get_r_squared_from_volcano(&self, point: Position) -> usize {
+        Point::from(point).distance_squared(&Point::from(self.volcano))
+    }
+
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+    type Input = Terrain;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Terrain, ParseError> {
+        Terrain::from_text(input)
+    }
+
+    fn part1(&self, grid: &Terrain) -> usize {
+        part_1(grid)
+    }
+
+    fn part2(&self, grid: &Terrain) -> usize {
+        part_2(grid)
+    }
+
+    fn part3(&self, grid: &Terrain) -> usize {
+        part_3(grid)
+    }
+}
+
+fn part_1(grid: &Terrain) -> usize {
+    let destruction = get_destruction_cells_bfs(grid, 10);
+    destruction.into_iter()
+        .filter(|(k, _)| *k <= 10)
+        .map(|(_, v)| v)
+        .sum::<usize>()
+}
+
+fn part_2(grid: &Terrain) -> usize {
+    let max_radius = grid.get_r_squared_from_volcano((0, 0));
+
+    let destruction = get_destruction_cells_bfs(grid, max_radius);
+    destruction.iter()
+        .max_by_key(|(_, v)| *v)
+        .map(|(k, v)| k * v)
+        .unwrap()
+}
+
+fn part_3(grid: &Terrain) -> usize {
+    let max_radius = grid.get_r_squared_from_volcano((0, 0));
+
+    let _destruction = get_destruction_cells_bfs(grid, max_radius);
+    0
+}
+
+fn get_destruction_cells_bfs(grid: &Terrain, radius: usize) -> HashMap<usize, usize> {
+    let r_squared = radius * radius;
+
+    let reachable = search::bfs([grid.volcano], |&pos| grid.cells.neighbours4(pos), |_| false);
+
+    let mut destruction: HashMap<usize, usize> = HashMap::new();
+
+    for &current in reachable.visited() {
+        let r_squared_curr = grid.get_r_squared_from_volcano(current);
+        if r_squared_curr <= r_squared {
+            // Calculate the radius, ceil is needed to ensure that it fits within an integer step
+            let r = (r_squared_curr as f64).sqrt().ceil() as usize;
+            destruction.entry(r).and_modify(|v| *v += grid.cells[current]).or_insert(grid.cells[current]);
+        }
+    }
+    destruction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The examples of the quest statement were not kept with this quest, so these grids are
+    // invented, small enough to check by hand: every cell lies within radius 10 of the volcano in
+    // part 1. Part 3 is not solved yet and has no test.
+
+    #[test]
+    fn part_1_small_grid() {
+        assert_eq!(Day17.part1(&Day17.parse("123\n4@5\n678").unwrap()), 36);
+    }
+
+    #[test]
+    fn part_2_small_grid() {
+        // The corners are destroyed at radius 2 and hold more than the edges at radius 1
+        assert_eq!(Day17.part2(&Day17.parse("129\n4@5\n678").unwrap()), 48);
+    }
+
+    #[test]
+    fn malformed_terrain_is_reported() {
+        let error = Day17.parse("123\n4@5\n6#8").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 2: expected a number, found \"#\"");
+        let error = Day17.parse("123\n456").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a volcano @");
+    }
+}
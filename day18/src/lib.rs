@@ -0,0 +1,401 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use utils::Solver;
+use utils::parse::{ParseError, Span, list};
+
+// A branch feeds a plant from one of the plants before it, or is a free branch fed by the plant
+// itself when its test case turns it on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Branch {
+    parent: Option<usize>,
+    thickness: isize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Description {
+    id: usize,
+    thickness: isize,
+    branches: Vec<Branch>,
+}
+
+// The plants in the order of the notes, and the test cases that say which free branches are on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garden {
+    plants: Vec<Description>,
+    tests: Vec<Vec<bool>>,
+}
+
+impl Garden {
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        let mut plants: Vec<Description> = vec![];
+        let mut tests = vec![];
+
+        for line in Span::lines(input) {
+            if line.text.starts_with("Plant ") {
+                let (id, thickness) = line.strip_prefix("Plant ")?
+                    .delimited("", ":")?
+                    .split_once(" with thickness ")?;
+                plants.push(Description { id: id.number()?, thickness: thickness.number()?, branches: vec![] });
+            }
+            else if line.text.starts_with("- ") {
+                let (source, thickness) = line.strip_prefix("- ")?.split_once(" with thickness ")?;
+                let parent = if source.text == "free branch" {
+                    None
+                }
+                else {
+                    let parent = source.strip_prefix("branch to Plant ")?;
+                    let id = parent.number::<usize>()?;
+                    // The energy of a plant needs the energy of its parents first
+                    if !plants.iter().any(|plant| plant.id == id) {
+                        return Err(parent.error(format!("plant {} is not described before this branch", id)));
+                    }
+                    Some(id)
+                };
+                let branch = Branch { parent, thickness: thickness.number()? };
+                plants.last_mut()
+                    .ok_or_else(|| line.error("expected a plant before its branches"))?
+                    .branches
+                    .push(branch);
+            }
+            else {
+                tests.push(list(line, " ", |active| match active.text {
+                    "0" => Ok(false),
+                    "1" => Ok(true),
+                    _ => Err(active.error(format!("expected 0 or 1, found \"{}\"", active.text))),
+                })?);
+            }
+        }
+
+        if plants.is_empty() {
+            return Err(Span::end(input).error("expected a plant"));
+        }
+        Ok(Garden { plants, tests })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Plant {
+    id: usize,
+    energy: isize,
+    pos_parents: Vec<usize>,
+    neg_parents: Vec<usize>,
+}
+
+impl Plant {
+    fn from_description(description: &Description, plants: &HashMap<usize, Plant>, instruction_map: Option<&HashMap<usize, bool>>) -> Plant {
+        let id = description.id;
+        let mut energy = 0;
+        let mut pos_parents = Vec::new();
+        let mut neg_parents = Vec::new();
+
+        for branch in &description.branches {
+            match branch.parent {
+                None => {
+                    if let Some(instruction) = instruction_map
+                        && instruction.get(&id) == Some(&false) {
+                            continue;
+                    }
+                    energy += branch.thickness;
+                }
+                Some(parent) => {
+                    if branch.thickness >= 0 {
+                        pos_parents.push(parent);
+                    }
+                    else {
+                        neg_parents.push(parent);
+                    }
+
+                    // Parsing made sure that the parents are described before their children
+                    energy += plants[&parent].energy * branch.thickness;
+                }
+            }
+        }
+
+        if energy < description.thickness {
+            energy = 0;
+        }
+
+        Plant { id, energy, pos_parents, neg_parents }
+
+    }
+
+    fn connected(description: &Description) -> impl Iterator<Item = (usize, isize)> + '_ {
+        description.branches.iter()
+            .filter_map(|branch| branch.parent.map(|parent| (parent, branch.thickness)))
+    }
+
+    fn new_max_possible(description: &Description, max_free: usize) -> Option<Plant> {
+        let id = description.id;
+        if id < max_free {
+            return None
+        }
+
+        let mut energy = 0;
+        let mut pos_parents = Vec::new();
+        let mut neg_parents = Vec::new();
+
+        for (parent, parent_thickness) in Self::connected(description) {
+            if parent > max_free {
+                return None
+            }
+            if parent_thickness >= 0 {
+                pos_parents.push(parent);
+            }
+            else {
+                neg_parents.push(parent);
+            }
+            if parent_thickness > 0 {
+                energy += parent_thickness;
+            }
+
+        }
+
+        if energy < description.thickness {
+            return Some(Plant { id, energy: 0 , pos_parents, neg_parents });
+        }
+
+        Some(Plant { id, energy , pos_parents, neg_parents })
+    }
+
+    fn useful_node(description: &Description, max_free: usize, not_useful: &HashMap<usize, Plant>) -> Option<Plant> {
+        let id = description.id;
+        if id < max_free {
+            return None
+        }
+        let mut pos_parents = Vec::new();
+        let mut neg_parents = Vec::new();
+
+        for (parent, parent_thickness) in Self::connected(description) {
+            if parent_thickness >= 0 {
+                pos_parents.push(parent);
+            }
+            else {
+                neg_parents.push(parent);
+            }
+
+
+            if parent > max_free {
+                return None
+            }
+
+        }
+
+        if pos_parents.iter().all(|p| not_useful.contains_key(p)) {
+            return None;
+        }
+
+        Some(Plant { id, energy: 0 , pos_parents, neg_parents })
+    }
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+    type Input = Garden;
+    type Answer = isize;
+
+    fn parse(&self, input: &str) -> Result<Garden, ParseError> {
+        Garden::parse(input)
+    }
+
+    fn part1(&self, garden: &Garden) -> isize {
+        part_1(garden)
+    }
+
+    fn part2(&self, garden: &Garden) -> isize {
+        part_2(garden)
+    }
+
+    fn part3(&self, garden: &Garden) -> isize {
+        part_3(garden)
+    }
+}
+
+fn part_1(garden: &Garden) -> isize {
+    get_max_value(garden, None)
+}
+
+fn part_2(garden: &Garden) -> isize {
+    garden.tests.iter()
+        .map(|test| try_instructions(test, garden))
+        .sum()
+}
+
+fn part_3(garden: &Garden) -> isize {
+    // The plants with free branches are not linked to grandchildren directly. Furthermore,
+    // the input shows that the grandchildren do not link to their parents with negative thicknesses.
+    // Moreover, some first children seem to be unable to be activated because their positive
+    // contributions are too low. This means that some nodes can be trimmed
+    let Some(first) = garden.tests.first() else {
+        return 0;
+    };
+    let num_free_plants = first.len();
+
+    let mut zero_plants : HashMap<usize, Plant> = HashMap::new();
+    let mut useful_plants : HashMap<usize, Plant> = HashMap::new();
+
+    garden.plants.iter()
+        .skip(num_free_plants)
+        .for_each(|descr| {
+            if let Some(plant) = Plant::new_max_possible(descr, num_free_plants) {
+                if plant.energy == 0 {
+                    zero_plants.insert(plant.id, plant);
+                }
+                else {
+                    useful_plants.insert(plant.id, plant);
+                }
+            }
+        });
+
+    let new_max = std::cmp::max(
+        zero_plants.keys().max(),
+        useful_plants.keys().max()
+    ).copied().unwrap_or(0);
+
+    garden.plants.iter()
+        .skip(new_max)
+        .for_each(|descr| {
+            if let Some(plant) = Plant::useful_node(descr, num_free_plants, &zero_plants) {
+                useful_plants.insert(plant.id, plant);
+            }
+        });
+
+    let mut overall_positive: HashSet<usize> = HashSet::new();
+    let mut overall_negative: HashSet<usize> = HashSet::new();
+    useful_plants.iter()
+        .for_each(|(_, plant)| {
+            overall_positive.extend(plant.pos_parents.iter());
+            overall_negative.extend(plant.neg_parents.iter());
+        });
+
+    let possible_combinations = all_combinations_itertools(&overall_positive, &overall_negative);
+    let mut max_possible = 0;
+    possible_combinations
+        .for_each(|combination|{
+            let try_instruction: HashMap<usize, bool> = (1..num_free_plants + 1)
+                .map(|x| (x, combination.contains(&x)))
+                .collect();
+            let max_try = get_max_value(garden, Some(&try_instruction));
+            if max_try > max_possible {
+                max_possible = max_try;
+            }
+        });
+
+    garden.tests.iter()
+        .map(|test| try_instructions(test, garden))
+        .filter(|&result| result != 0)
+        .map(|result| max_possible - result)
+        .sum()
+}
+
+fn all_combinations_itertools(good: &HashSet<usize>, bad: &HashSet<usize>) -> impl Iterator<Item = HashSet<usize>> {
+    let always_included: Vec<usize> = good.difference(bad).copied().collect();
+    let overlapping: Vec<usize> = good.intersection(bad).copied().collect();
+
+    (0..=overlapping.len()).flat_map(move |k| {
+        let always_included = always_included.clone();  // Clone for each k
+        let overlapping = overlapping.clone();          // Clone for each k
+
+        overlapping.into_iter().combinations(k).map(move |combo| {
+            let mut set: HashSet<usize> = always_included.iter().copied().collect();
+            set.extend(combo);
+            set
+        })
+    })
+}
+
+fn try_instructions(test: &[bool], garden: &Garden) -> isize {
+    let instruction = get_instruction(test);
+    get_max_value(garden, Some(&instruction))
+}
+
+fn get_max_value(garden: &Garden, instruction: Option<&HashMap<usize, bool>>) -> isize {
+    // The energy of the last plant, which is the one with the highest id
+    let mut plants: HashMap<usize, Plant> = HashMap::new();
+    garden.plants.iter()
+        .for_each(|descr| {
+            let plant = Plant::from_description(descr, &plants, instruction);
+            plants.insert(plant.id, plant);
+        });
+    plants.iter()
+        .max_by_key(|(k, _)| *k)
+        .map_or(0, |(_, v)| v.energy)
+}
+
+fn get_instruction(test: &[bool]) -> HashMap<usize, bool> {
+    // The free branches of the plants 1, 2, ... are on or off in the order of the test case
+    test.iter()
+        .enumerate()
+        .map(|(i, &active)| (i + 1, active))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Only the example of part 1 was kept. Parts 2 and 3 also need the test cases that follow the
+    // plants, and part 3 trims plants based on properties of the real input, which a small example
+    // does not necessarily share.
+
+    const EXAMPLE: &str = "\
+Plant 1 with thickness 1:
+- free branch with thickness 1
+
+Plant 2 with thickness 1:
+- free branch with thickness 1
+
+Plant 3 with thickness 1:
+- free branch with thickness 1
+
+Plant 4 with thickness 17:
+- branch to Plant 1 with thickness 15
+- branch to Plant 2 with thickness 3
+
+Plant 5 with thickness 24:
+- branch to Plant 2 with thickness 11
+- branch to Plant 3 with thickness 13
+
+Plant 6 with thickness 15:
+- branch to Plant 3 with thickness 14
+
+Plant 7 with thickness 10:
+- branch to Plant 4 with thickness 15
+- branch to Plant 5 with thickness 21
+- branch to Plant 6 with thickness 34";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(Day18.part1(&Day18.parse(EXAMPLE).unwrap()), 774);
+    }
+
+    #[test]
+    fn parses_plants_and_test_cases() {
+        let garden = Day18.parse("Plant 1 with thickness 1:\n- free branch with thickness 1\n\nPlant 2 with thickness 3:\n- branch to Plant 1 with thickness -4\n\n\n1\n0").unwrap();
+        assert_eq!(garden.plants[1], Description {
+            id: 2,
+            thickness: 3,
+            branches: vec![Branch { parent: Some(1), thickness: -4 }],
+        });
+        assert_eq!(garden.plants[0].branches, vec![Branch { parent: None, thickness: 1 }]);
+        assert_eq!(garden.tests, vec![vec![true], vec![false]]);
+    }
+
+    #[test]
+    fn malformed_notes_are_reported() {
+        let error = Day18.parse("Plant 1 with thickness x:").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 24: expected a number, found \"x\"");
+        let error = Day18.parse("Plant 1 with thickness 1:\n- branch to Plant 2 with thickness 1").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 19: plant 2 is not described before this branch");
+        let error = Day18.parse("Plant 1 with thickness 1:\n- branch to Tree 2 with thickness 1").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Day18.parse("- free branch with thickness 1").unwrap_err();
+        assert_eq!(error.message, "expected a plant before its branches");
+        let error = Day18.parse("Plant 1 with thickness 1:\n- free branch with thickness 1\n\n1 2").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 3: expected 0 or 1, found \"2\"");
+        assert_eq!(Day18.parse("").unwrap_err().message, "expected a plant");
+    }
+}
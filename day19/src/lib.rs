@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use utils::Solver;
+use utils::parse::{ParseError, Span, numbers};
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+    type Input = HashMap<isize, Vec<[isize; 3]>>;
+    type Answer = isize;
+
+    fn parse(&self, input: &str) -> Result<HashMap<isize, Vec<[isize; 3]>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+        part_1(obstacles)
+    }
+
+    fn part2(&self, obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+        part_2(obstacles)
+    }
+
+    fn part3(&self, obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+        part_3(obstacles)
+    }
+}
+
+fn part_1(obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+    bfs(obstacles)
+}
+
+fn part_2(obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+    bfs(obstacles)
+}
+
+fn part_3(obstacles: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+    bfs(obstacles)
+}
+
+fn bfs(triplets: &HashMap<isize, Vec<[isize; 3]>>) -> isize {
+    let mut x_points = triplets.keys().cloned().collect::<Vec<_>>();
+    x_points.sort();
+    x_points.insert(0, 0);
+
+    let mut current: HashMap<isize, isize> = HashMap::new();
+    current.insert(0, 0);
+
+    for (i, x) in x_points.iter().enumerate() {
+        if i == x_points.len() - 1 {
+            break;
+        }
+        let mut new: HashMap<isize, isize> = HashMap::new();
+
+        for (&curr_y, &curr_flaps) in &current {
+            for opening in &triplets[&x_points[i + 1]] {
+                let dx = opening[0] - x;
+                // Each step in x-direction is either a flap or not (i.e. difference of two if one
+                // is swapped for the other)
+                let y_max = dx + curr_y;
+                let y_min = -dx + curr_y;
+                let parity = y_max % 2;
+                for y_new in opening[1]..opening[1] + opening[2] {
+                    if y_new >= y_min && y_new <= y_max && y_new % 2 == parity {
+                        let new_flaps = ((y_new - curr_y) + dx) / 2;
+                        new.entry(y_new)
+                            .and_modify(|v| *v = (*v).min(curr_flaps + new_flaps))
+                            .or_insert(curr_flaps + new_flaps);
+                    }
+                }
+            }
+        }
+        current = new;
+
+    }
+    *current.values().min().unwrap()
+}
+
+fn parse(input: &str) -> Result<HashMap<isize, Vec<[isize; 3]>>, ParseError> {
+    // Every line is an opening: its distance, the height of its bottom and its size
+    let mut obstacles = HashMap::new();
+    for line in Span::lines(input) {
+        let array: [isize; 3] = numbers::<isize>(line)?
+            .try_into()
+            .map_err(|_| line.error(format!("expected three numbers, found \"{}\"", line.text)))?;
+        obstacles.entry(array[0])
+            .and_modify(|v: &mut Vec<[isize; 3]>| v.push(array))
+            .or_insert(vec![array]);
+    }
+    Ok(obstacles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of part 3 was not kept with the quest. Part 3 flies through the openings the
+    // same way as parts 1 and 2, so bfs is checked on single openings where the flaps can be
+    // counted by hand: every step right either rises by one with a flap or falls by one without.
+
+    #[test]
+    fn part_1_example() {
+        let input = "7,7,2\n12,0,4\n15,5,3\n24,1,6\n28,5,5\n40,8,2";
+        assert_eq!(Day19.part1(&Day19.parse(input).unwrap()), 24);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let input = "7,7,2\n7,1,3\n12,0,4\n15,5,3\n24,1,6\n28,5,5\n40,3,3\n40,8,2";
+        assert_eq!(Day19.part2(&Day19.parse(input).unwrap()), 22);
+    }
+
+    #[test]
+    fn part_3_counts_the_flaps_to_an_opening() {
+        assert_eq!(Day19.part3(&Day19.parse("2,0,1").unwrap()), 1);
+        assert_eq!(Day19.part3(&Day19.parse("2,2,1").unwrap()), 2);
+        assert_eq!(Day19.part3(&Day19.parse("4,-4,1\n6,-2,1").unwrap()), 2);
+    }
+
+    #[test]
+    fn malformed_openings_are_reported() {
+        let error = Day19.parse("7,7,2\n12,x,4").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 4: expected a number, found \"x\"");
+        let error = Day19.parse("7,7,2\n\n12,0").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected three numbers, found \"12,0\"");
+    }
+}
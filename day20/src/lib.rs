@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use utils::grid::Grid;
+use utils::parse::{ParseError, Span};
+use utils::{Solver, search};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Empty,
+    Dot,
+    TUp,
+    TDown,
+    Start,
+    End,
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+    type Input = Grid<Tile>;
+    type Answer = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<Tile>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<Tile>) -> usize {
+        part_1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<Tile>) -> usize {
+        part_2(grid)
+    }
+
+    fn part3(&self, grid: &Grid<Tile>) -> usize {
+        part_3(grid)
+    }
+}
+
+fn part_1(grid: &Grid<Tile>) -> usize {
+    get_number_connected_trampolines(grid)
+}
+
+fn part_2(grid: &Grid<Tile>) -> usize {
+    let connected = find_connected_trampolines(grid);
+    let start = grid.index_of(grid.find(|tile| *tile == Tile::Start).unwrap());
+    let end = grid.index_of(grid.find(|tile| *tile == Tile::End).unwrap());
+    count_steps_from_start(&connected, start, end)
+}
+
+fn part_3(_grid: &Grid<Tile>) -> usize {
+    0
+}
+
+
+fn count_steps_from_start(connected: &HashMap<usize, Vec<usize>>, start: usize, end: usize) -> usize {
+    // All trampolines in connected are connected, so indexing is safe
+    search::bfs([start], |current| connected[current].iter().copied(), |&current| current == end)
+        .goal_distance()
+        .expect("End cannot be reached from the start")
+}
+
+fn get_number_connected_trampolines(grid: &Grid<Tile>) -> usize {
+    find_connected_trampolines(grid).values().flatten().collect::<Vec<&usize>>().len() / 2
+}
+
+fn find_connected_trampolines(grid: &Grid<Tile>) -> HashMap<usize, Vec<usize>> {
+    let mut all = find_horizontally_connected_trampolines(grid);
+    let vertical = find_vertically_connected_trampolines(grid);
+
+    vertical.iter()
+        .for_each(|(k, v)| {
+            all.entry(*k)
+                .and_modify(|hor_v| hor_v.extend(v))
+                .or_insert(v.clone());
+        });
+    all
+}
+
+fn find_horizontally_connected_trampolines(grid: &Grid<Tile>) -> HashMap<usize, Vec<usize>> {
+    let mut connected: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    grid.positions()
+        .filter(|&(_, col)| col > 0)
+        .for_each(|(row, col)| {
+            if is_trampoline(&grid[(row, col)]) && is_trampoline(&grid[(row, col - 1)]) {
+                let current = grid.index_of((row, col));
+                connected.entry(current)
+                    .and_modify(|v| v.push(current - 1))
+                    .or_insert(vec![current - 1]);
+                connected.entry(current - 1)
+                    .and_modify(|v| v.push(current))
+                    .or_insert(vec![current]);
+            }
+        });
+    connected
+}
+
+fn find_vertically_connected_trampolines(grid: &Grid<Tile>) -> HashMap<usize, Vec<usize>> {
+    let mut connected: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    grid.positions()
+        .filter(|&(row, _)| row > 0)
+        .for_each(|(row, col)| {
+            let tile = &grid[(row, col)];
+            let tile_above = &grid[(row - 1, col)];
+            if is_trampoline(tile) && is_trampoline(tile_above) {
+                let current = grid.index_of((row, col));
+                let above = grid.index_of((row - 1, col));
+
+                let masked_curr = mask_start_end_tile(tile.clone(), row, col);
+                let masked_above = mask_start_end_tile(tile_above.clone(), row - 1, col);
+
+                if let (Tile::TDown, Tile::TUp) = (masked_curr, masked_above) {
+                    connected.entry(current)
+                        .and_modify(|v| v.push(above))
+                        .or_insert(vec![above]);
+                    connected.entry(above)
+                        .and_modify(|v| v.push(current))
+                        .or_insert(vec![current]);
+                };
+            }
+        });
+    connected
+}
+
+fn is_trampoline(tile: &Tile) -> bool {
+    matches!(tile, Tile::Start | Tile::End | Tile::TUp | Tile::TDown)
+}
+
+fn mask_start_end_tile(tile: Tile, row: usize, col: usize) -> Tile {
+    if tile == Tile::Start || tile == Tile::End {
+        if (row + col).is_multiple_of(2) {
+            Tile::TDown
+        }
+        else {
+            Tile::TUp
+        }
+    }
+    else {
+        tile
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let chars = Grid::try_parse(input, |tile| match tile.text {
+        "#" | "." | "S" | "E" | "T" => tile.char(),
+        _ => Err(tile.error(format!("expected '#', '.', 'S', 'E' or 'T', found '{}'", tile.text))),
+    })?;
+    if chars.is_empty() {
+        return Err(Span::end(input).error("expected a triangle of trampolines"));
+    }
+
+    let tiles = chars.iter()
+        .map(|((row, col), &c)| match c {
+            '#' => Tile::Empty,
+            '.' => Tile::Dot,
+            'S' => Tile::Start,
+            'E' => Tile::End,
+            _ => {
+                if (row + col) % 2 == 0 {
+                    Tile::TDown
+                }
+                else {
+                    Tile::TUp
+                }
+            }
+        })
+        .collect();
+    Ok(Grid::new(chars.width(), chars.height(), tiles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of part 2 was not kept with the quest, so part 2 is only checked on invented
+    // triangles, where the shortest route can be counted by hand. Part 3 is not solved yet.
+
+    #[test]
+    fn part_1_example() {
+        let input = "T#TTT###T##\n.##TT#TT##.\n..T###T#T..\n...##TT#...\n....T##....\n.....#.....";
+        assert_eq!(Day20.part1(&Day20.parse(input).unwrap()), 7);
+    }
+
+    #[test]
+    fn malformed_triangles_are_reported() {
+        let error = Day20.parse("STE\n.T").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row of 3 cells, found 2");
+        let error = Day20.parse("STx").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 3: expected '#', '.', 'S', 'E' or 'T', found 'x'");
+        assert_eq!(Day20.parse("").unwrap_err().message, "expected a triangle of trampolines");
+    }
+
+    #[test]
+    fn part_2_jumps_along_a_row() {
+        assert_eq!(Day20.part2(&Day20.parse("STTTE").unwrap()), 4);
+        assert_eq!(Day20.part2(&Day20.parse("#STE#\n##.##").unwrap()), 2);
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use utils::{Quest, quest_key, try_read_input};
+
+use crate::run::{Outcome, catch_panic};
+use crate::table::{Align, print_table};
+
+pub const DEFAULT_RUNS: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_times(mut times: Vec<Duration>) -> Stats {
+        times.sort();
+        Stats { min: times[0], median: times[times.len() / 2], max: times[times.len() - 1] }
+    }
+}
+
+pub struct Bench {
+    pub event: u16,
+    pub day: u8,
+    pub part: u8,
+    // Statistics of parsing and solving, or the outcome that stopped the part from being benchmarked
+    pub result: Result<(Stats, Stats), Outcome>,
+}
+
+pub fn bench_part(quest: &dyn Quest, part: u8, path: &Path, runs: u32) -> Bench {
+    let result = match try_read_input(path) {
+        Ok(input) => time_runs(quest, part, &input, runs),
+        Err(e) => Err(Outcome::NoInput(e.to_string())),
+    };
+    Bench { event: quest.event(), day: quest.day(), part, result }
+}
+
+fn time_runs(quest: &dyn Quest, part: u8, input: &str, runs: u32) -> Result<(Stats, Stats), Outcome> {
+    let mut parse = Vec::new();
+    let mut solve = Vec::new();
+    for _ in 0..runs {
+        let (_, timing) = catch_panic(|| quest.timed(part, input))
+            .map_err(Outcome::Panicked)?
+            .map_err(Outcome::from)?;
+        parse.push(timing.parse);
+        solve.push(timing.solve);
+    }
+    Ok((Stats::from_times(parse), Stats::from_times(solve)))
+}
+
+// The baseline file stores the median times in nanoseconds of each benchmarked part, e.g.
+//
+// [day05.part1]
+// parse = 51200
+// solve = 1830400
+//
+// The quests of other events than the current one are keyed like [event2024-quest05.part1].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Medians {
+    parse: u64,
+    solve: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct Baseline {
+    days: BTreeMap<String, BTreeMap<String, Medians>>,
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Serialize(PathBuf, toml::ser::Error),
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Io(path, e) => write!(f, "could not access baseline file {}: {}", path.display(), e),
+            BaselineError::Parse(path, e) => write!(f, "could not parse baseline file {}: {}", path.display(), e),
+            BaselineError::Serialize(path, e) => write!(f, "could not write baseline file {}: {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BaselineError::Io(_, e) => Some(e),
+            BaselineError::Parse(_, e) => Some(e),
+            BaselineError::Serialize(_, e) => Some(e),
+        }
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, BaselineError> {
+        let text = fs::read_to_string(path).map_err(|e| BaselineError::Io(path.to_path_buf(), e))?;
+        let days = toml::from_str(&text).map_err(|e| BaselineError::Parse(path.to_path_buf(), e))?;
+        Ok(Baseline { days })
+    }
+
+    pub fn save(benches: &[Bench], path: &Path) -> Result<(), BaselineError> {
+        // Parts that are not benchmarked this time keep their stored times
+        let mut baseline = match Baseline::load(path) {
+            Err(BaselineError::Io(_, e)) if e.kind() == io::ErrorKind::NotFound => Baseline::default(),
+            other => other?,
+        };
+        for bench in benches {
+            if let Ok((parse, solve)) = &bench.result {
+                let medians = Medians { parse: nanos(parse.median), solve: nanos(solve.median) };
+                baseline.days.entry(quest_key(bench.event, bench.day))
+                    .or_default()
+                    .insert(part_key(bench.part), medians);
+            }
+        }
+
+        let text = toml::to_string(&baseline.days).map_err(|e| BaselineError::Serialize(path.to_path_buf(), e))?;
+        fs::write(path, text).map_err(|e| BaselineError::Io(path.to_path_buf(), e))
+    }
+
+    fn medians(&self, event: u16, day: u8, part: u8) -> Option<Medians> {
+        self.days.get(&quest_key(event, day))?.get(&part_key(part)).copied()
+    }
+}
+
+fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+fn nanos(time: Duration) -> u64 {
+    u64::try_from(time.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn format_change(time: Duration, baseline: u64) -> String {
+    if baseline == 0 {
+        return String::from("-");
+    }
+    let change = (time.as_nanos() as f64 / baseline as f64 - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
+
+pub fn print_benches(benches: &[Bench], runs: u32, baseline: Option<&Baseline>) {
+    let mut columns = vec![
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Stage", Align::Left),
+        ("Min", Align::Right),
+        ("Median", Align::Right),
+        ("Max", Align::Right),
+    ];
+    if baseline.is_some() {
+        columns.push(("Baseline", Align::Right));
+        columns.push(("Change", Align::Right));
+    }
+
+    let mut rows = vec![];
+    let mut skipped = vec![];
+    for bench in benches {
+        let (parse, solve) = match &bench.result {
+            Ok(stats) => stats,
+            Err(outcome) => {
+                skipped.push(format!("Day {} part {}: {}", bench.day, bench.part, outcome.describe()));
+                continue;
+            }
+        };
+        let medians = baseline.and_then(|baseline| baseline.medians(bench.event, bench.day, bench.part));
+
+        let stages = [
+            ("parse", parse, medians.map(|medians| medians.parse)),
+            ("solve", solve, medians.map(|medians| medians.solve)),
+        ];
+        for (stage, stats, stored) in stages {
+            let mut row = vec![
+                bench.day.to_string(),
+                bench.part.to_string(),
+                stage.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            ];
+            if baseline.is_some() {
+                match stored {
+                    Some(stored) => {
+                        row.push(format!("{:.2?}", Duration::from_nanos(stored)));
+                        row.push(format_change(stats.median, stored));
+                    }
+                    None => row.extend([String::from("-"), String::from("-")]),
+                }
+            }
+            rows.push(row);
+        }
+    }
+    print_table(&columns, &rows);
+
+    println!("\n{} runs per part", runs);
+    if !skipped.is_empty() {
+        println!("\nSkipped:");
+        for message in skipped {
+            println!("{}", message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::CURRENT_EVENT;
+
+    fn millis(times: &[u64]) -> Vec<Duration> {
+        times.iter().map(|&time| Duration::from_millis(time)).collect()
+    }
+
+    fn bench(event: u16, day: u8, part: u8, parse: u64, solve: u64) -> Bench {
+        let stats = |time| Stats::from_times(millis(&[time]));
+        Bench { event, day, part, result: Ok((stats(parse), stats(solve))) }
+    }
+
+    #[test]
+    fn stats_of_unsorted_times() {
+        let stats = Stats::from_times(millis(&[30, 10, 50, 20, 40]));
+        assert_eq!(stats, Stats { min: Duration::from_millis(10), median: Duration::from_millis(30), max: Duration::from_millis(50) });
+        // The upper of the two middle times
+        assert_eq!(Stats::from_times(millis(&[4, 1, 3, 2])).median, Duration::from_millis(3));
+        assert_eq!(Stats::from_times(millis(&[7])).median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn saves_medians_in_nanoseconds() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.toml");
+
+        Baseline::save(&[bench(CURRENT_EVENT, 5, 1, 2, 30), bench(2024, 5, 1, 4, 50)], &path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.contains("[day05.part1]\nparse = 2000000\nsolve = 30000000\n"), "{}", text);
+        assert!(text.contains("[event2024-quest05.part1]\nparse = 4000000\nsolve = 50000000\n"), "{}", text);
+        let medians = Baseline::load(&path).unwrap().medians(CURRENT_EVENT, 5, 1).unwrap();
+        assert_eq!((medians.parse, medians.solve), (2000000, 30000000));
+    }
+
+    #[test]
+    fn keeps_parts_that_were_not_benchmarked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.toml");
+        fs::write(&path, "[day05.part1]\nparse = 1\nsolve = 2\n\n[day05.part2]\nparse = 3\nsolve = 4\n").unwrap();
+
+        let skipped = Bench { event: CURRENT_EVENT, day: 7, part: 1, result: Err(Outcome::NoInput(String::from("no input"))) };
+        Baseline::save(&[bench(CURRENT_EVENT, 5, 2, 1, 1), skipped], &path).unwrap();
+
+        let baseline = Baseline::load(&path).unwrap();
+        let medians = |day, part| baseline.medians(CURRENT_EVENT, day, part).map(|medians| (medians.parse, medians.solve));
+        assert_eq!(medians(5, 1), Some((1, 2)));
+        assert_eq!(medians(5, 2), Some((1000000, 1000000)));
+        assert_eq!(medians(7, 1), None);
+    }
+
+    #[test]
+    fn invalid_baseline_is_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.toml");
+        fs::write(&path, "[day05.part1]\nparse = \"fast\"\n").unwrap();
+
+        let result = Baseline::save(&[bench(CURRENT_EVENT, 5, 1, 1, 1)], &path);
+        assert!(matches!(result, Err(BaselineError::Parse(..))));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "[day05.part1]\nparse = \"fast\"\n");
+    }
+
+    #[test]
+    fn changes_relative_to_the_baseline() {
+        assert_eq!(format_change(Duration::from_nanos(150), 100), "+50.0%");
+        assert_eq!(format_change(Duration::from_nanos(75), 100), "-25.0%");
+        assert_eq!(format_change(Duration::from_nanos(100), 100), "+0.0%");
+        assert_eq!(format_change(Duration::from_nanos(100), 0), "-");
+    }
+}
@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+
+use utils::DAYS;
+use utils::args::{ArgsError, parse_number};
+
+pub const USAGE: &str = "\
+Usage: runner [--event YYYY] [--day N] [--part K] [--input PATH] [--all] [--verify]
+              [--answers PATH] [--bench] [--runs N] [--save-baseline PATH] [--baseline PATH]
+              [--fetch] [--submit] [--root PATH]
+
+Options:
+    --event YYYY   Run the quests of event YYYY instead of the event in everybody-codes.toml, which
+                   is the current event by default
+    --day N        Run the quest of day N
+    --part K       Only run part K (1, 2 or 3)
+    --input PATH   Use PATH as input instead of inputs/dayNNptK.txt, requires --day and --part
+    --all          Run every quest
+    --verify       Compare the answers with the answers file, for every quest unless --day is given
+    --answers PATH Use PATH as answers file instead of answers.toml
+    --bench        Time parsing and solving of each part over repeated runs
+    --runs N       Number of runs per part when benchmarking, 10 by default
+    --save-baseline PATH
+                   Store the median times of the benchmark in PATH
+    --baseline PATH
+                   Compare the benchmark with the median times stored in PATH
+    --fetch        Download the inputs that are missing, using the session in everybody-codes.toml
+    --submit       Solve the part and submit its answer, requires --day and --part
+    --root PATH    Resolve inputs and the answers, config and history files from PATH, instead of
+                   $EVERYBODY_CODES_ROOT or the workspace the runner was built in
+    --help         Show this message";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub event: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub all: bool,
+    pub verify: bool,
+    pub answers: Option<PathBuf>,
+    pub bench: bool,
+    pub runs: Option<u32>,
+    pub save_baseline: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub fetch: bool,
+    pub submit: bool,
+    pub root: Option<PathBuf>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, ArgsError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--event" => parsed.event = Some(parse_number(&arg, args.next(), 2024..=9999)?),
+                "--day" => parsed.day = Some(parse_number(&arg, args.next(), DAYS)?),
+                "--part" => parsed.part = Some(parse_number(&arg, args.next(), 1..=3)?),
+                "--input" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.input = Some(PathBuf::from(path));
+                }
+                "--all" => parsed.all = true,
+                "--verify" => parsed.verify = true,
+                "--answers" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.answers = Some(PathBuf::from(path));
+                }
+                "--bench" => parsed.bench = true,
+                "--runs" => parsed.runs = Some(parse_number(&arg, args.next(), 1..=1_000_000)?),
+                "--save-baseline" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.save_baseline = Some(PathBuf::from(path));
+                }
+                "--baseline" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.baseline = Some(PathBuf::from(path));
+                }
+                "--fetch" => parsed.fetch = true,
+                "--submit" => parsed.submit = true,
+                "--root" => {
+                    let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    parsed.root = Some(PathBuf::from(path));
+                }
+                "--help" | "-h" => return Err(ArgsError::Help),
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+
+        if parsed.all && parsed.day.is_some() {
+            return Err(ArgsError::Conflict(String::from("--all cannot be combined with --day")));
+        }
+        if !parsed.all && !parsed.verify && parsed.day.is_none() {
+            return Err(ArgsError::Conflict(String::from("either --day or --all is required")));
+        }
+        if parsed.input.is_some() && (parsed.day.is_none() || parsed.part.is_none()) {
+            return Err(ArgsError::Conflict(String::from("--input requires both --day and --part")));
+        }
+        if parsed.verify && parsed.input.is_some() {
+            return Err(ArgsError::Conflict(String::from("--verify cannot be combined with --input")));
+        }
+        if parsed.answers.is_some() && !parsed.verify {
+            return Err(ArgsError::Conflict(String::from("--answers requires --verify")));
+        }
+        if parsed.bench && parsed.verify {
+            return Err(ArgsError::Conflict(String::from("--bench cannot be combined with --verify")));
+        }
+        if !parsed.bench && (parsed.runs.is_some() || parsed.save_baseline.is_some() || parsed.baseline.is_some()) {
+            return Err(ArgsError::Conflict(String::from("--runs, --save-baseline and --baseline require --bench")));
+        }
+        if parsed.fetch && (parsed.verify || parsed.bench || parsed.input.is_some()) {
+            return Err(ArgsError::Conflict(String::from("--fetch cannot be combined with --verify, --bench or --input")));
+        }
+        if parsed.submit && (parsed.day.is_none() || parsed.part.is_none()) {
+            return Err(ArgsError::Conflict(String::from("--submit requires both --day and --part")));
+        }
+        if parsed.submit && (parsed.verify || parsed.bench || parsed.fetch || parsed.input.is_some()) {
+            return Err(ArgsError::Conflict(String::from("--submit cannot be combined with --verify, --bench, --fetch or --input")));
+        }
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, ArgsError> {
+        Args::parse(args.split_whitespace().map(String::from))
+    }
+
+    fn conflict(args: &str) -> String {
+        match parse(args) {
+            Err(ArgsError::Conflict(message)) => message,
+            result => panic!("expected a conflict for {:?}, got {:?}", args, result),
+        }
+    }
+
+    #[test]
+    fn valid_combinations() {
+        assert_eq!(parse("--day 5 --part 2"), Ok(Args { day: Some(5), part: Some(2), ..Args::default() }));
+        assert_eq!(parse("--event 2024 --all"), Ok(Args { event: Some(2024), all: true, ..Args::default() }));
+        assert!(parse("--all").is_ok());
+        // Verifying checks every quest unless a day is given
+        assert!(parse("--verify").is_ok());
+        assert!(parse("--verify --day 3 --answers answers.toml").is_ok());
+        assert!(parse("--day 5 --part 2 --input example.txt").is_ok());
+        assert!(parse("--all --bench --runs 3 --baseline old.toml --save-baseline new.toml").is_ok());
+        assert!(parse("--all --fetch").is_ok());
+        assert!(parse("--day 5 --part 2 --submit").is_ok());
+    }
+
+    #[test]
+    fn day_or_all() {
+        assert_eq!(conflict(""), "either --day or --all is required");
+        assert_eq!(conflict("--bench"), "either --day or --all is required");
+        assert_eq!(conflict("--all --day 5"), "--all cannot be combined with --day");
+    }
+
+    #[test]
+    fn input_needs_a_single_part() {
+        assert_eq!(conflict("--day 5 --input example.txt"), "--input requires both --day and --part");
+        assert_eq!(conflict("--all --part 1 --input example.txt"), "--input requires both --day and --part");
+        assert_eq!(conflict("--day 5 --part 1 --input example.txt --verify"), "--verify cannot be combined with --input");
+    }
+
+    #[test]
+    fn mode_specific_flags() {
+        assert_eq!(conflict("--day 5 --answers answers.toml"), "--answers requires --verify");
+        assert_eq!(conflict("--verify --bench"), "--bench cannot be combined with --verify");
+        for flags in ["--runs 3", "--baseline old.toml", "--save-baseline new.toml"] {
+            assert_eq!(conflict(&format!("--all {}", flags)), "--runs, --save-baseline and --baseline require --bench");
+        }
+    }
+
+    #[test]
+    fn fetch_and_submit_stand_alone() {
+        assert_eq!(conflict("--all --fetch --bench"), "--fetch cannot be combined with --verify, --bench or --input");
+        assert_eq!(conflict("--fetch --verify"), "--fetch cannot be combined with --verify, --bench or --input");
+        assert_eq!(conflict("--day 5 --submit"), "--submit requires both --day and --part");
+        assert_eq!(conflict("--all --part 1 --submit"), "--submit requires both --day and --part");
+        assert_eq!(
+            conflict("--day 5 --part 1 --submit --fetch"),
+            "--submit cannot be combined with --verify, --bench, --fetch or --input",
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(parse("--day 20").unwrap().day, Some(20));
+        assert_eq!(parse("--day 21"), Err(ArgsError::InvalidValue(String::from("--day"), String::from("21"))));
+        assert_eq!(parse("--day 5 --part 4"), Err(ArgsError::InvalidValue(String::from("--part"), String::from("4"))));
+        assert_eq!(parse("--event 24 --all"), Err(ArgsError::InvalidValue(String::from("--event"), String::from("24"))));
+        assert_eq!(parse("--all --bench --runs 0"), Err(ArgsError::InvalidValue(String::from("--runs"), String::from("0"))));
+        assert_eq!(parse("--day"), Err(ArgsError::MissingValue(String::from("--day"))));
+        assert_eq!(parse("--help"), Err(ArgsError::Help));
+        assert_eq!(parse("--quiet"), Err(ArgsError::Unknown(String::from("--quiet"))));
+    }
+}
@@ -0,0 +1,242 @@
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+use client::{
+    CONFIG_FILE, Client, ClientError, Config, Fetched, HISTORY_FILE, Submitted, Verdict, fetch_inputs, submit_answer,
+};
+use utils::args::ArgsError;
+use utils::{CURRENT_EVENT, PARTS, Quest, input_path, resolve, root, set_root};
+
+mod bench;
+mod cli;
+mod run;
+mod table;
+mod verify;
+
+use bench::{Baseline, DEFAULT_RUNS, bench_part, print_benches};
+use cli::{Args, USAGE};
+use run::{Outcome, print_runs, run_part};
+use verify::{Answers, print_verification};
+
+static QUESTS: [&dyn Quest; 20] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+];
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(root) = &args.root {
+        set_root(root);
+    }
+
+    let event = match args.event {
+        Some(event) => event,
+        None => match configured_event() {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+    };
+    let solved: Vec<&dyn Quest> = QUESTS.iter()
+        .copied()
+        .filter(|quest| quest.event() == event)
+        .collect();
+    let quests: Vec<&dyn Quest> = match args.day {
+        Some(day) => match solved.iter().find(|quest| quest.day() == day) {
+            Some(&quest) => vec![quest],
+            None => {
+                eprintln!("error: day {} of event {} has not been solved yet", day, event);
+                return ExitCode::from(2);
+            }
+        },
+        None if solved.is_empty() => {
+            eprintln!("error: no quest of event {} has been solved yet", event);
+            return ExitCode::from(2);
+        }
+        None => solved,
+    };
+    let parts: Vec<u8> = args.part.map_or(PARTS.to_vec(), |part| vec![part]);
+
+    if args.fetch {
+        return fetch(&quests, &parts);
+    }
+
+    // Load the answers before running anything, so a broken answers file is reported immediately
+    let answers = if args.verify {
+        let path = args.answers.clone().unwrap_or_else(|| resolve("answers.toml"));
+        match Answers::load(&path) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+        }
+    }
+    else {
+        None
+    };
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+        None => None,
+    };
+
+    if args.submit {
+        return submit(quests[0], parts[0]);
+    }
+
+    if args.bench {
+        let runs = args.runs.unwrap_or(DEFAULT_RUNS);
+        let mut benches = Vec::new();
+        for quest in quests {
+            for &part in &parts {
+                let path = args.input.clone().unwrap_or_else(|| resolve(input_path(quest.event(), quest.day(), part)));
+                benches.push(bench_part(quest, part, &path, runs));
+            }
+        }
+        print_benches(&benches, runs, baseline.as_ref());
+
+        if let Some(path) = &args.save_baseline {
+            if let Err(e) = Baseline::save(&benches, path) {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+            println!("\nSaved baseline to {}", path.display());
+        }
+        let success = !benches.iter().any(|bench| bench.result.as_ref().is_err_and(Outcome::is_failure));
+        return if success { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+    }
+
+    let mut runs = Vec::new();
+    for quest in quests {
+        for &part in &parts {
+            let path = args.input.clone().unwrap_or_else(|| resolve(input_path(quest.event(), quest.day(), part)));
+            runs.push(run_part(quest, part, &path));
+        }
+    }
+
+    let success = match answers {
+        Some(answers) => print_verification(&runs, &answers),
+        None => {
+            print_runs(&runs);
+            !runs.iter().any(|run| run.outcome.is_failure())
+        }
+    };
+    if success { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+// The event of the config file, or the current event if there is no config file
+fn configured_event() -> Result<u16, ClientError> {
+    match Config::load(&resolve(CONFIG_FILE)) {
+        Ok(config) => Ok(config.event),
+        Err(ClientError::Io(_, e)) if e.kind() == io::ErrorKind::NotFound => Ok(CURRENT_EVENT),
+        Err(e) => Err(e),
+    }
+}
+
+fn load_client() -> Option<Client> {
+    match Config::load(&resolve(CONFIG_FILE)) {
+        Ok(config) => Some(Client::new(config)),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            None
+        }
+    }
+}
+
+fn fetch(quests: &[&dyn Quest], parts: &[u8]) -> ExitCode {
+    let Some(client) = load_client() else {
+        return ExitCode::from(2);
+    };
+
+    // A failing day is reported, but does not stop the remaining days from being fetched
+    let mut success = true;
+    for quest in quests {
+        match fetch_inputs(&client, quest.event(), quest.day(), parts, &root()) {
+            Ok(fetched) => {
+                for (part, fetched) in fetched {
+                    match fetched {
+                        Fetched::Cached(path) => println!("Day {} part {}: already downloaded to {}", quest.day(), part, path.display()),
+                        Fetched::Downloaded(path) => println!("Day {} part {}: downloaded to {}", quest.day(), part, path.display()),
+                        Fetched::Locked => println!("Day {} part {}: locked, solve the previous part first", quest.day(), part),
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", quest.day(), e);
+                success = false;
+            }
+        }
+    }
+    if success { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+fn submit(quest: &dyn Quest, part: u8) -> ExitCode {
+    let Some(client) = load_client() else {
+        return ExitCode::from(2);
+    };
+
+    let run = run_part(quest, part, &resolve(input_path(quest.event(), quest.day(), part)));
+    let Outcome::Solved(answer) = run.outcome else {
+        eprintln!("Day {} part {}: {}, nothing to submit", quest.day(), part, run.outcome.describe());
+        return ExitCode::FAILURE;
+    };
+
+    let prefix = format!("Day {} part {}", quest.day(), part);
+    match submit_answer(&client, &resolve(HISTORY_FILE), quest.event(), quest.day(), part, &answer) {
+        Ok(Submitted::Posted(verdict)) => {
+            println!("{}: {} is {}", prefix, answer, verdict);
+            if verdict == Verdict::Correct { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        }
+        Ok(Submitted::AlreadyCorrect(correct)) => {
+            println!("{}: already solved with {}, not submitting {}", prefix, correct, answer);
+            if correct == answer { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        }
+        Ok(Submitted::KnownWrong) => {
+            println!("{}: {} was already rejected before, not submitting it again", prefix, answer);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}: {}", prefix, e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use utils::{Quest, QuestError, try_read_input};
+
+use crate::table::{Align, print_table};
+
+pub enum Outcome {
+    Solved(String),
+    // The input could not be read, so the part did not run
+    NoInput(String),
+    // The input could be read, but not parsed
+    InvalidInput(String),
+    // The input could be parsed, but the part has no answer for it
+    Unsolved(String),
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn describe(&self) -> String {
+        match self {
+            Outcome::Solved(answer) => answer.clone(),
+            Outcome::NoInput(message) => message.clone(),
+            Outcome::InvalidInput(message) => format!("invalid input: {}", message),
+            Outcome::Unsolved(message) => format!("no answer: {}", message),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+        }
+    }
+
+    // A part without an input is skipped, every other part without an answer failed
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_) | Outcome::NoInput(_))
+    }
+}
+
+impl From<QuestError> for Outcome {
+    fn from(e: QuestError) -> Outcome {
+        match e {
+            QuestError::InvalidInput(e) => Outcome::InvalidInput(e.to_string()),
+            QuestError::Unsolvable(message) => Outcome::Unsolved(message),
+        }
+    }
+}
+
+pub struct Run {
+    pub event: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub time: Option<Duration>,
+}
+
+pub fn run_part(quest: &dyn Quest, part: u8, path: &Path) -> Run {
+    let (outcome, time) = match try_read_input(path) {
+        Ok(input) => {
+            let start = Instant::now();
+            let result = catch_panic(|| quest.solve(part, &input));
+            let time = start.elapsed();
+            match result {
+                Ok(Ok(answer)) => (Outcome::Solved(answer), Some(time)),
+                Ok(Err(e)) => (Outcome::from(e), Some(time)),
+                Err(message) => (Outcome::Panicked(message), Some(time)),
+            }
+        }
+        Err(e) => (Outcome::NoInput(e.to_string()), None),
+    };
+
+    Run { event: quest.event(), day: quest.day(), part, outcome, time }
+}
+
+// Runs a part and returns the message if it panics. Panics are reported in the tables, so the
+// default message on stderr is only noise while the part runs, but not for the runner itself.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.map_err(|payload| panic_message(&*payload).to_string())
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    }
+    else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    }
+    else {
+        "unknown panic"
+    }
+}
+
+pub fn format_time(time: Option<Duration>) -> String {
+    time.map_or(String::from("-"), |time| format!("{:.2?}", time))
+}
+
+pub fn print_runs(runs: &[Run]) {
+    let columns = [("Day", Align::Right), ("Part", Align::Right), ("Answer", Align::Left), ("Time", Align::Left)];
+    let rows: Vec<Vec<String>> = runs.iter()
+        .map(|run| vec![run.day.to_string(), run.part.to_string(), run.outcome.describe(), format_time(run.time)])
+        .collect();
+    print_table(&columns, &rows);
+
+    let total: Duration = runs.iter().filter_map(|run| run.time).sum();
+    println!("\nTotal time: {:.2?}", total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panics_with_their_message() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(catch_panic(|| panic!("No volcano found")), Err::<(), _>(String::from("No volcano found")));
+        let day = 17;
+        assert_eq!(catch_panic(|| panic!("Day {} failed", day)), Err::<(), _>(String::from("Day 17 failed")));
+    }
+
+    #[test]
+    fn only_solved_and_skipped_parts_succeed() {
+        let unsolved = Outcome::from(QuestError::Unsolvable(String::from("the result overflows")));
+        assert_eq!(unsolved.describe(), "no answer: the result overflows");
+        assert!(unsolved.is_failure());
+        assert!(Outcome::Panicked(String::from("boom")).is_failure());
+        assert!(!Outcome::Solved(String::from("42")).is_failure());
+        assert!(!Outcome::NoInput(String::from("no file")).is_failure());
+    }
+}
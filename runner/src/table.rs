@@ -0,0 +1,35 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+pub fn print_table(columns: &[(&str, Align)], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = columns.iter()
+        .map(|(title, _)| title.chars().count())
+        .collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header: Vec<String> = columns.iter().map(|(title, _)| title.to_string()).collect();
+    print_row(&header, columns, &widths);
+    println!("{}", widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<String>>().join("-+-"));
+    for row in rows {
+        print_row(row, columns, &widths);
+    }
+}
+
+fn print_row(row: &[String], columns: &[(&str, Align)], widths: &[usize]) {
+    let cells: Vec<String> = row.iter()
+        .zip(columns)
+        .zip(widths)
+        .map(|((cell, (_, align)), &width)| match align {
+            Align::Left => format!("{:<width$}", cell),
+            Align::Right => format!("{:>width$}", cell),
+        })
+        .collect();
+    println!("{}", cells.join(" | ").trim_end());
+}
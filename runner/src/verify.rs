@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use utils::parse_quest_key;
+
+use crate::run::{Outcome, Run};
+use crate::table::{Align, print_table};
+
+// The answers file has a table per quest with the expected answer of each part, e.g.
+//
+// [day05]
+// part1 = 1234
+// part2 = "some text"
+//
+// [event2024-quest05]
+// part1 = 5678
+//
+// The quests of the current event are keyed by day, those of other events by event and quest.
+// Parts without an expected answer are reported as missing.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+    part3: Option<Expected>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    quests: BTreeMap<(u16, u8), DayAnswers>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    InvalidQuest(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => write!(f, "could not read answers file {}: {}", path.display(), e),
+            AnswersError::Parse(path, e) => write!(f, "could not parse answers file {}: {}", path.display(), e),
+            AnswersError::InvalidQuest(path, key) => {
+                write!(f, "invalid quest '{}' in answers file {}, expected e.g. [day05] or [event2024-quest05]", key, path.display())
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Io(_, e) => Some(e),
+            AnswersError::Parse(_, e) => Some(e),
+            AnswersError::InvalidQuest(..) => None,
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let text = fs::read_to_string(path).map_err(|e| AnswersError::Io(path.to_path_buf(), e))?;
+        Answers::parse(&text, path)
+    }
+
+    fn parse(text: &str, path: &Path) -> Result<Answers, AnswersError> {
+        let tables: BTreeMap<String, DayAnswers> = toml::from_str(text)
+            .map_err(|e| AnswersError::Parse(path.to_path_buf(), e))?;
+
+        let mut quests = BTreeMap::new();
+        for (key, answers) in tables {
+            let quest = parse_quest_key(&key)
+                .ok_or_else(|| AnswersError::InvalidQuest(path.to_path_buf(), key.clone()))?;
+            quests.insert(quest, answers);
+        }
+        Ok(Answers { quests })
+    }
+
+    pub fn expected(&self, event: u16, day: u8, part: u8) -> Option<String> {
+        let answers = self.quests.get(&(event, day))?;
+        let expected = match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            3 => answers.part3.as_ref(),
+            _ => None,
+        }?;
+        match expected {
+            Expected::Number(number) => Some(number.to_string()),
+            Expected::Text(text) => Some(text.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    // No expected answer is stored, or there is no input to run the part on
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+pub fn check(run: &Run, expected: Option<&str>) -> Status {
+    match (&run.outcome, expected) {
+        (Outcome::NoInput(_), _) | (_, None) => Status::Missing,
+        (Outcome::Solved(answer), Some(expected)) if answer == expected => Status::Pass,
+        _ => Status::Fail,
+    }
+}
+
+pub fn print_verification(runs: &[Run], answers: &Answers) -> bool {
+    // Returns whether none of the parts failed
+    let columns = [
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Status", Align::Left),
+        ("Expected", Align::Left),
+        ("Answer", Align::Left),
+    ];
+    let mut statuses = vec![];
+    let rows: Vec<Vec<String>> = runs.iter()
+        .map(|run| {
+            let expected = answers.expected(run.event, run.day, run.part);
+            let status = check(run, expected.as_deref());
+            statuses.push(status);
+
+            vec![
+                run.day.to_string(),
+                run.part.to_string(),
+                status.to_string(),
+                expected.unwrap_or_else(|| String::from("-")),
+                run.outcome.describe(),
+            ]
+        })
+        .collect();
+    print_table(&columns, &rows);
+
+    let count = |status: Status| statuses.iter().filter(|&&other| other == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass), count(Status::Fail), count(Status::Missing),
+    );
+    count(Status::Fail) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::CURRENT_EVENT;
+
+    fn parse(text: &str) -> Result<Answers, AnswersError> {
+        Answers::parse(text, Path::new("answers.toml"))
+    }
+
+    fn run(outcome: Outcome) -> Run {
+        Run { event: CURRENT_EVENT, day: 5, part: 1, outcome, time: None }
+    }
+
+    #[test]
+    fn numbers_and_text() {
+        let answers = parse("[day05]\npart1 = 1234\npart2 = \"some text\"\n\n[day12]\npart3 = -7").unwrap();
+        let expected = |day, part| answers.expected(CURRENT_EVENT, day, part);
+        assert_eq!(expected(5, 1).as_deref(), Some("1234"));
+        assert_eq!(expected(5, 2).as_deref(), Some("some text"));
+        assert_eq!(expected(5, 3), None);
+        assert_eq!(expected(12, 3).as_deref(), Some("-7"));
+        assert_eq!(expected(6, 1), None);
+        assert_eq!(expected(5, 4), None);
+    }
+
+    #[test]
+    fn keys_are_quests() {
+        assert_eq!(parse("[day5]\npart1 = 1").unwrap().expected(CURRENT_EVENT, 5, 1).as_deref(), Some("1"));
+        let answers = parse("[day05]\npart1 = 1\n\n[event2024-quest05]\npart1 = 2").unwrap();
+        assert_eq!(answers.expected(CURRENT_EVENT, 5, 1).as_deref(), Some("1"));
+        assert_eq!(answers.expected(2024, 5, 1).as_deref(), Some("2"));
+        for key in ["five", "day", "dayx", "day256", "Day05", "event2024-day05"] {
+            let error = parse(&format!("[{}]\npart1 = 1", key)).unwrap_err();
+            assert!(matches!(&error, AnswersError::InvalidQuest(_, invalid) if invalid == key), "{}", key);
+        }
+        let error = parse("[day05]\npart4 = 1").unwrap_err();
+        assert!(matches!(error, AnswersError::Parse(..)));
+    }
+
+    #[test]
+    fn pass_fail_and_missing() {
+        let solved = run(Outcome::Solved(String::from("1234")));
+        assert_eq!(check(&solved, Some("1234")), Status::Pass);
+        assert_eq!(check(&solved, Some("1235")), Status::Fail);
+        assert_eq!(check(&solved, None), Status::Missing);
+
+        assert_eq!(check(&run(Outcome::NoInput(String::from("no file"))), Some("1234")), Status::Missing);
+        assert_eq!(check(&run(Outcome::Panicked(String::from("boom"))), Some("1234")), Status::Fail);
+        assert_eq!(check(&run(Outcome::InvalidInput(String::from("line 1"))), Some("1234")), Status::Fail);
+        assert_eq!(check(&run(Outcome::Unsolved(String::from("overflow"))), Some("1234")), Status::Fail);
+        assert_eq!(check(&run(Outcome::Panicked(String::from("boom"))), None), Status::Missing);
+    }
+}
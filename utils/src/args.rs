@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+// Errors of the command line arguments of the runner and add-day
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    Help,
+    MissingValue(String),
+    InvalidValue(String, String),
+    Unknown(String),
+    Conflict(String),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Help => write!(f, "help requested"),
+            ArgsError::MissingValue(flag) => write!(f, "{} needs a value", flag),
+            ArgsError::InvalidValue(flag, value) => write!(f, "invalid value '{}' for {}", value, flag),
+            ArgsError::Unknown(arg) => write!(f, "unknown argument '{}'", arg),
+            ArgsError::Conflict(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+// The value of a flag as a number within the range
+pub fn parse_number<T: FromStr + PartialOrd>(flag: &str, value: Option<String>, range: RangeInclusive<T>) -> Result<T, ArgsError> {
+    let value = value.ok_or_else(|| ArgsError::MissingValue(flag.to_string()))?;
+    value.parse::<T>()
+        .ok()
+        .filter(|number| range.contains(number))
+        .ok_or_else(|| ArgsError::InvalidValue(flag.to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_within_the_range() {
+        assert_eq!(parse_number("--part", Some(String::from("3")), 1..=3), Ok(3));
+        for value in ["0", "4", "-1", "x", ""] {
+            assert_eq!(
+                parse_number("--part", Some(value.to_string()), 1..=3),
+                Err(ArgsError::InvalidValue(String::from("--part"), value.to_string())),
+            );
+        }
+        assert_eq!(parse_number::<u8>("--part", None, 1..=3), Err(ArgsError::MissingValue(String::from("--part"))));
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(ArgsError::InvalidValue(String::from("--day"), String::from("x")).to_string(), "invalid value 'x' for --day");
+        assert_eq!(ArgsError::Unknown(String::from("--days")).to_string(), "unknown argument '--days'");
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The states of a simulation up to and including one full repetition. Step 0 is the initial
+// state, every step after `start` repeats with the given `period`.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub period: usize,
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    pub fn index_at(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        }
+        else {
+            self.start + (step - self.start) % self.period
+        }
+    }
+
+    pub fn state_at(&self, step: usize) -> &S {
+        &self.states[self.index_at(step)]
+    }
+
+    pub fn accumulate(&self, steps: usize, mut value: impl FnMut(&S) -> usize) -> usize {
+        // Sum of the value of the states after step 1 up to and including the given step,
+        // without simulating every step
+        let values: Vec<usize> = self.states.iter().map(&mut value).collect();
+        let until = |step: usize| -> usize {
+            // Sum over the steps 0..=step
+            if step < self.start {
+                return values[..=step].iter().sum();
+            }
+            let head: usize = values[..self.start].iter().sum();
+            let cycle = &values[self.start..];
+            let count = step - self.start + 1;
+            head + count / self.period * cycle.iter().sum::<usize>()
+                + cycle[..count % self.period].iter().sum::<usize>()
+        };
+        until(steps) - values[0]
+    }
+}
+
+pub fn find_cycle<S: Eq + Hash + Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut current = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&current) {
+            let period = states.len() - start;
+            return Cycle { start, period, states };
+        }
+        seen.insert(current.clone(), states.len());
+        let next = step(&current);
+        states.push(current);
+        current = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, then 2, 3, 4 repeating
+    fn lasso() -> Cycle<usize> {
+        find_cycle(0, |&state| if state < 4 { state + 1 } else { 2 })
+    }
+
+    fn simulate(steps: usize) -> Vec<usize> {
+        let mut states = vec![0];
+        for _ in 0..steps {
+            let state = states[states.len() - 1];
+            states.push(if state < 4 { state + 1 } else { 2 });
+        }
+        states
+    }
+
+    #[test]
+    fn finds_the_start_and_period() {
+        let cycle = lasso();
+        assert_eq!((cycle.start, cycle.period), (2, 3));
+        assert_eq!(cycle.states(), [0, 1, 2, 3, 4]);
+
+        let pure = find_cycle(0, |&state| (state + 1) % 3);
+        assert_eq!((pure.start, pure.period), (0, 3));
+    }
+
+    #[test]
+    fn index_before_and_inside_the_cycle() {
+        let cycle = lasso();
+        assert_eq!(cycle.index_at(1), 1);
+        assert_eq!(cycle.index_at(2), 2);
+        assert_eq!(cycle.index_at(5), 2);
+        assert_eq!(cycle.index_at(7), 4);
+        assert_eq!(cycle.index_at(9), 3);
+        let states = simulate(30);
+        for (step, state) in states.iter().enumerate() {
+            assert_eq!(cycle.state_at(step), state);
+        }
+    }
+
+    #[test]
+    fn accumulates_without_simulating() {
+        let cycle = lasso();
+        assert_eq!(cycle.accumulate(0, |&state| state), 0);
+        assert_eq!(cycle.accumulate(1, |&state| state), 1);
+        // Ends in the middle of the second repetition
+        assert_eq!(cycle.accumulate(7, |&state| state), 19);
+        let states = simulate(30);
+        for steps in 0..=30 {
+            assert_eq!(cycle.accumulate(steps, |&state| state), states[1..=steps].iter().sum::<usize>());
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Span};
+
+// Positions are (row, col), with row 0 at the top
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+const ALL: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid of {}x{} needs {} cells", width, height, width * height);
+        Grid { cells, width, height }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn parse<S: AsRef<str>>(lines: &[S], mut map: impl FnMut(char) -> T) -> Grid<T> {
+        Grid::parse_with_position(lines, |_, c| map(c))
+    }
+
+    pub fn parse_with_position<S: AsRef<str>>(lines: &[S], mut map: impl FnMut(Position, char) -> T) -> Grid<T> {
+        let height = lines.len();
+        let width = lines.first().map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            assert_eq!(line.chars().count(), width, "Row {} of the grid has a different width", row);
+            cells.extend(line.chars().enumerate().map(|(col, c)| map((row, col), c)));
+        }
+
+        Grid { cells, width, height }
+    }
+
+    // Like parse, but reports ragged rows and cells that map rejects with their position. Every
+    // cell is handed to map as a span of a single character.
+    pub fn try_parse<'a>(input: &'a str, mut map: impl FnMut(Span<'a>) -> Result<T, ParseError>) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in Span::lines(input) {
+            let row: Vec<T> = line.text.char_indices()
+                .enumerate()
+                .map(|(col, (offset, c))| {
+                    let text = &line.text[offset..offset + c.len_utf8()];
+                    map(Span { text, line: line.line, column: line.column + col })
+                })
+                .collect::<Result<_, _>>()?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(line.error(format!("expected a row of {} cells, found {}", width, row.len())));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        Ok(Grid { cells, width: width.unwrap_or(0), height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn index_of(&self, pos: Position) -> usize {
+        pos.0 * self.width + pos.1
+    }
+
+    // The position of a cell, or None if the index lies outside the grid, which includes every
+    // index of a grid without columns
+    pub fn position_of(&self, index: usize) -> Option<Position> {
+        if index < self.cells.len() {
+            Some((index / self.width, index % self.width))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if pos.0 < self.height && pos.1 < self.width {
+            self.cells.get(self.index_of(pos))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if pos.0 < self.height && pos.1 < self.width {
+            let index = self.index_of(pos);
+            self.cells.get_mut(index)
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn in_bounds(&self, row: isize, col: isize) -> bool {
+        row >= 0 && row < self.height as isize && col >= 0 && col < self.width as isize
+    }
+
+    pub fn checked_position(&self, row: isize, col: isize) -> Option<Position> {
+        self.in_bounds(row, col).then_some((row as usize, col as usize))
+    }
+
+    pub fn offset(&self, pos: Position, delta: (isize, isize)) -> Option<Position> {
+        self.checked_position(pos.0 as isize + delta.0, pos.1 as isize + delta.1)
+    }
+
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn diagonal_neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        DIAGONAL.iter().filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL.iter().filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells.iter()
+            .position(predicate)
+            .and_then(|index| self.position_of(index))
+    }
+
+    pub fn map<U>(&self, map: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(map).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn render(&self, mut to_char: impl FnMut(&T) -> char) -> String {
+        self.cells.chunks(self.width.max(1))
+            .map(|row| row.iter().map(&mut to_char).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        assert!(pos.0 < self.height && pos.1 < self.width, "Position {:?} is outside the grid", pos);
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        assert!(pos.0 < self.height && pos.1 < self.width, "Position {:?} is outside the grid", pos);
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.cells[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row, cells) in self.cells.chunks(self.width.max(1)).enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in cells {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(positions: impl Iterator<Item = Position>) -> Vec<Position> {
+        let mut positions: Vec<Position> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn parses_rows_from_the_top() {
+        let grid = Grid::parse(&["123", "456"], |c| c.to_digit(10).unwrap());
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+        assert_eq!(grid[(0, 2)], 3);
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        let positions = Grid::parse_with_position(&["ab"], |pos, _| pos);
+        assert_eq!(positions.cells(), &[(0, 0), (0, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 1 of the grid has a different width")]
+    fn ragged_rows() {
+        Grid::parse(&["abc", "ab"], |c| c);
+    }
+
+    #[test]
+    fn indices_and_positions() {
+        let grid = Grid::filled(4, 3, 0);
+        assert_eq!(grid.index_of((2, 1)), 9);
+        assert_eq!(grid.position_of(9), Some((2, 1)));
+        assert_eq!(grid.position_of(3), Some((0, 3)));
+        assert_eq!(grid.position_of(12), None);
+        for index in 0..grid.len() {
+            assert_eq!(grid.position_of(index).map(|pos| grid.index_of(pos)), Some(index));
+        }
+    }
+
+    #[test]
+    fn grids_without_cells_have_no_positions() {
+        let empty: Grid<u8> = Grid::filled(0, 3, 0);
+        assert_eq!(empty.position_of(0), None);
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(Grid::filled(3, 0, 0).position_of(0), None);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::filled(3, 3, ' ');
+        assert_eq!(sorted(grid.neighbours4((0, 0))), vec![(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbours8((0, 0))), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.diagonal_neighbours((0, 0))), vec![(1, 1)]);
+        assert_eq!(sorted(grid.neighbours4((2, 1))), vec![(1, 1), (2, 0), (2, 2)]);
+        assert_eq!(sorted(grid.diagonal_neighbours((1, 2))), vec![(0, 1), (2, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn offsets() {
+        let grid = Grid::filled(3, 2, ' ');
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+        assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        assert_eq!(grid.checked_position(-1, 5), None);
+        assert!(grid.in_bounds(1, 2));
+        assert!(!grid.in_bounds(2, 0));
+    }
+
+    #[test]
+    fn render_and_display() {
+        let grid = Grid::new(2, 2, vec![1, 0, 0, 1]);
+        assert_eq!(grid.render(|&cell| if cell == 1 { '#' } else { '.' }), "#.\n.#");
+        assert_eq!(grid.to_string(), "10\n01");
+        assert_eq!(grid.find(|&cell| cell == 0), Some((0, 1)));
+    }
+
+    #[test]
+    fn try_parse_reports_positions() {
+        let grid = Grid::try_parse("12\n\n34\n", Span::number::<u32>).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 0)], 3);
+
+        let error = Grid::try_parse("12\n3x", Span::number::<u32>).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected a number, found \"x\"");
+        let error = Grid::try_parse("123\n45", Span::char).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row of 3 cells, found 2");
+
+        let empty = Grid::try_parse("", Span::char).unwrap();
+        assert!(empty.is_empty());
+    }
+}
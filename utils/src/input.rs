@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    PermissionDenied(PathBuf),
+    InvalidUtf8(PathBuf),
+    Empty(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl InputError {
+    pub fn path(&self) -> &Path {
+        match self {
+            InputError::NotFound(path)
+            | InputError::PermissionDenied(path)
+            | InputError::InvalidUtf8(path)
+            | InputError::Empty(path)
+            | InputError::Io(path, _) => path,
+        }
+    }
+
+    fn from_io(path: &Path, error: io::Error) -> InputError {
+        let path = path.to_path_buf();
+        match error.kind() {
+            io::ErrorKind::NotFound => InputError::NotFound(path),
+            io::ErrorKind::PermissionDenied => InputError::PermissionDenied(path),
+            _ => InputError::Io(path, error),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input file {} does not exist", path.display()),
+            InputError::PermissionDenied(path) => write!(f, "no permission to read input file {}", path.display()),
+            InputError::InvalidUtf8(path) => write!(f, "input file {} is not valid UTF-8", path.display()),
+            InputError::Empty(path) => write!(f, "input file {} is empty", path.display()),
+            InputError::Io(path, error) => write!(f, "could not read input file {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+pub fn normalize(input: &str) -> String {
+    // Inputs saved by different editors or tools differ in BOM, line endings and trailing
+    // whitespace. Reduce them to a single form: LF line endings and no trailing whitespace
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines: Vec<&str> = input.lines()
+        .map(|line| line.trim_end())
+        .collect();
+    lines.join("\n").trim_end().to_string()
+}
+
+pub fn split_lines(input: &str) -> Vec<String> {
+    input.lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.to_string())
+        .collect()
+}
+
+pub fn split_blocks(input: &str) -> Vec<String> {
+    // Blocks are separated by one or more blank lines
+    let mut blocks = vec![];
+    let mut current: Vec<&str> = vec![];
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(current.join("\n"));
+                current.clear();
+            }
+        }
+        else {
+            current.push(line.trim_end());
+        }
+    }
+
+    if !current.is_empty() {
+        blocks.push(current.join("\n"));
+    }
+    blocks
+}
+
+pub fn try_read_input(filename: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = filename.as_ref();
+    let bytes = fs::read(path).map_err(|e| InputError::from_io(path, e))?;
+    let input = String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8(path.to_path_buf()))?;
+    let input = normalize(&input);
+
+    if input.is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(input)
+}
+
+pub fn try_read_lines(filename: impl AsRef<Path>) -> Result<Vec<String>, InputError> {
+    Ok(split_lines(&try_read_input(filename)?))
+}
+
+pub fn try_read_blocks(filename: impl AsRef<Path>) -> Result<Vec<String>, InputError> {
+    Ok(split_blocks(&try_read_input(filename)?))
+}
+
+pub fn read_input(filename: impl AsRef<Path>) -> String {
+    try_read_input(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn read_lines(filename: impl AsRef<Path>) -> Vec<String> {
+    try_read_lines(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn read_blocks(filename: impl AsRef<Path>) -> Vec<String> {
+    try_read_blocks(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, "a,b\nc\n").unwrap();
+        assert_eq!(try_read_input(&path).unwrap(), "a,b\nc");
+        assert_eq!(try_read_lines(&path).unwrap(), vec!["a,b", "c"]);
+    }
+
+    #[test]
+    fn missing_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.txt");
+        let error = try_read_input(&path).unwrap_err();
+        assert!(matches!(error, InputError::NotFound(_)));
+        assert_eq!(error.path(), path);
+        assert_eq!(error.to_string(), format!("input file {} does not exist", path.display()));
+    }
+
+    #[test]
+    fn empty_input() {
+        // Whitespace only is as good as empty
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("empty.txt");
+        fs::write(&path, " \n\n").unwrap();
+        assert!(matches!(try_read_input(&path), Err(InputError::Empty(_))));
+        assert!(matches!(try_read_blocks(&path), Err(InputError::Empty(_))));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("binary.txt");
+        fs::write(&path, [0x66, 0xff, 0xfe]).unwrap();
+        let error = try_read_lines(&path).unwrap_err();
+        assert!(matches!(error, InputError::InvalidUtf8(_)));
+        assert!(error.to_string().ends_with("is not valid UTF-8"));
+    }
+
+    #[test]
+    fn normalizes_line_endings_and_whitespace() {
+        assert_eq!(normalize("\u{feff}a \r\n\r\n\r\nb\r\n"), "a\n\n\nb");
+        assert_eq!(normalize("a\t\nb  \n\n  \n"), "a\nb");
+        assert_eq!(normalize("  indented\r\n"), "  indented");
+    }
+
+    #[test]
+    fn blocks_are_split_on_blank_lines() {
+        assert_eq!(split_blocks("a\nb\n\n\n \nc \n"), vec!["a\nb", "c"]);
+        assert_eq!(split_blocks("\n\na\n"), vec!["a"]);
+        assert!(split_blocks("").is_empty());
+    }
+
+    #[test]
+    fn reads_blocks_of_any_editor() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blocks.txt");
+        fs::write(&path, "\u{feff}a \r\n\r\n\r\nb\r\n").unwrap();
+        assert_eq!(try_read_input(&path).unwrap(), "a\n\n\nb");
+        assert_eq!(try_read_blocks(&path).unwrap(), vec!["a", "b"]);
+        assert_eq!(try_read_lines(&path).unwrap(), vec!["a", "b"]);
+    }
+}
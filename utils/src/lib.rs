@@ -1,20 +1,21 @@
-use std::fs;
+pub mod args;
+pub mod cycle;
+pub mod grid;
+mod input;
+pub mod memo;
+pub mod parse;
+pub mod point;
+mod quest;
+mod root;
+pub mod search;
+mod solver;
 
-pub fn read_input(filename: &str) -> String {
-    fs::read_to_string(filename)
-        .expect("Something went wrong reading the file")
-}
-
-pub fn read_lines(filename: &str) -> Vec<String> {
-    read_input(filename)
-        .lines()
-        .filter_map(|x| {
-            if x.is_empty() {
-                None
-            }
-            else {
-                Some(x.to_string())
-            }
-        })
-        .collect()
-}
\ No newline at end of file
+pub use input::{
+    InputError, normalize, read_blocks, read_input, read_lines, split_blocks, split_lines, try_read_blocks,
+    try_read_input, try_read_lines,
+};
+pub use quest::{
+    CURRENT_EVENT, DAYS, PARTS, Quest, QuestError, Timing, example_path, input_path, parse_quest_key, quest_key, run_quest,
+};
+pub use root::{ROOT_VAR, resolve, root, set_root};
+pub use solver::{IntoAnswer, Solver};
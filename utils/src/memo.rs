@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub size: usize,
+}
+
+// Cache for a recursive function. The function computes a value through get_or_insert_with,
+// which hands the memo back to it, so the recursive calls go through the same cache.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo { cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Memo<K, V>) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats { hits: self.hits, misses: self.misses, size: self.cache.len() }
+    }
+
+    // Forgets the cached values and the stats, e.g. when the next part uses different rules
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_insert_with(n, |memo| {
+            if n < 2 {
+                n
+            }
+            else {
+                fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+            }
+        })
+    }
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        // Every number is computed once, and all but the first two are looked up once again
+        assert_eq!(memo.stats(), MemoStats { hits: 88, misses: 91, size: 91 });
+        assert_eq!(memo.get(&10), Some(&55));
+
+        memo.clear();
+        assert_eq!(memo.stats(), MemoStats::default());
+        assert_eq!(memo.get(&10), None);
+    }
+}
@@ -0,0 +1,237 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Small parsers for the input shapes that keep coming back. Every piece of text is a Span that
+// remembers where it came from, so a malformed input is reported with its line and column.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+// Lines and columns start at 1, columns are counted in characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str) -> Span<'a> {
+        Span { text, line: 1, column: 1 }
+    }
+
+    // The non-empty lines of the input, like split_lines
+    pub fn lines(input: &'a str) -> impl Iterator<Item = Span<'a>> {
+        input.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| Span { text: line, line: i + 1, column: 1 })
+    }
+
+    // The empty span after the last line, to report input that is missing altogether
+    pub fn end(input: &'a str) -> Span<'a> {
+        Span { text: "", line: input.lines().count() + 1, column: 1 }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError { line: self.line, column: self.column, message: message.into() }
+    }
+
+    // The part of the span starting at a byte offset
+    fn skip(&self, offset: usize) -> Span<'a> {
+        Span {
+            text: &self.text[offset..],
+            line: self.line,
+            column: self.column + self.text[..offset].chars().count(),
+        }
+    }
+
+    fn take(&self, length: usize) -> Span<'a> {
+        Span { text: &self.text[..length], ..*self }
+    }
+
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let mut offset = 0;
+        self.text.split(separator)
+            .map(move |part| {
+                let span = self.skip(offset).take(part.len());
+                offset += part.len() + separator.len();
+                span
+            })
+    }
+
+    pub fn split_once(self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let offset = self.text.find(separator)
+            .ok_or_else(|| self.error(format!("expected \"{}\" in \"{}\"", separator, self.text)))?;
+        Ok((self.take(offset), self.skip(offset + separator.len())))
+    }
+
+    pub fn trim(self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let trimmed = self.skip(start);
+        trimmed.take(trimmed.text.trim_end().len())
+    }
+
+    // The text between an opening and a closing delimiter, e.g. the numbers in [1,2]
+    pub fn delimited(self, open: &str, close: &str) -> Result<Span<'a>, ParseError> {
+        if !self.text.starts_with(open) || !self.text.ends_with(close) || self.text.len() < open.len() + close.len() {
+            return Err(self.error(format!("expected \"{}...{}\", found \"{}\"", open, close, self.text)));
+        }
+        Ok(self.skip(open.len()).take(self.text.len() - open.len() - close.len()))
+    }
+
+    // The text after a fixed prefix, e.g. the number in "Plant 4"
+    pub fn strip_prefix(self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        if !self.text.starts_with(prefix) {
+            return Err(self.error(format!("expected \"{}...\", found \"{}\"", prefix, self.text)));
+        }
+        Ok(self.skip(prefix.len()))
+    }
+
+    pub fn number<T: FromStr>(self) -> Result<T, ParseError> {
+        self.text.parse::<T>()
+            .map_err(|_| self.error(format!("expected a number, found \"{}\"", self.text)))
+    }
+
+    pub fn char(self) -> Result<char, ParseError> {
+        let mut chars = self.text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(self.error(format!("expected a single character, found \"{}\"", self.text))),
+        }
+    }
+}
+
+pub fn list<'a, T>(span: Span<'a>, separator: &'a str, item: impl Fn(Span<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    span.split(separator)
+        .map(item)
+        .collect()
+}
+
+// Comma separated numbers, e.g. 10,5,1,10
+pub fn numbers<T: FromStr>(span: Span) -> Result<Vec<T>, ParseError> {
+    list(span, ",", Span::number)
+}
+
+// A key and a value separated by a colon, e.g. 58:5,3,7
+pub fn key_value<'a, K, V>(
+    span: Span<'a>,
+    key: impl Fn(Span<'a>) -> Result<K, ParseError>,
+    value: impl Fn(Span<'a>) -> Result<V, ParseError>,
+) -> Result<(K, V), ParseError> {
+    let (k, v) = span.split_once(":")?;
+    Ok((key(k)?, value(v)?))
+}
+
+// A key followed by what it leads to, e.g. A > b,c
+pub fn rule<'a, K, V>(
+    span: Span<'a>,
+    key: impl Fn(Span<'a>) -> Result<K, ParseError>,
+    value: impl Fn(Span<'a>) -> Result<V, ParseError>,
+) -> Result<(K, Vec<V>), ParseError> {
+    let (k, values) = span.split_once(" > ")?;
+    Ok((key(k)?, list(values, ",", value)?))
+}
+
+// An inclusive range, e.g. 3-7, where a single number is a range of length one
+pub fn range<T: FromStr + Copy>(span: Span) -> Result<(T, T), ParseError> {
+    match span.split_once("-") {
+        Ok((lo, hi)) => Ok((lo.number()?, hi.number()?)),
+        Err(_) => {
+            let n = span.number()?;
+            Ok((n, n))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+// A turn followed by a number, e.g. L3 or R12
+pub fn turn<T: FromStr>(span: Span) -> Result<(Turn, T), ParseError> {
+    let turn = match span.text.chars().next() {
+        Some('L') => Turn::Left,
+        Some('R') => Turn::Right,
+        _ => return Err(span.error(format!("expected L or R, found \"{}\"", span.text))),
+    };
+    Ok((turn, span.skip(1).number()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_keep_their_position() {
+        let parts: Vec<Span> = Span::new("ab,c,,de").split(",").collect();
+        let columns: Vec<(&str, usize)> = parts.iter().map(|span| (span.text, span.column)).collect();
+        assert_eq!(columns, vec![("ab", 1), ("c", 4), ("", 6), ("de", 7)]);
+    }
+
+    #[test]
+    fn lines_skip_empty_lines() {
+        let lines: Vec<(&str, usize)> = Span::lines("a\n\nb").map(|span| (span.text, span.line)).collect();
+        assert_eq!(lines, vec![("a", 1), ("b", 3)]);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(super::numbers::<isize>(Span::new("10,-5,1")), Ok(vec![10, -5, 1]));
+        let error = super::numbers::<usize>(Span::new("1,2,x3")).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.to_string(), "line 1, column 5: expected a number, found \"x3\"");
+    }
+
+    #[test]
+    fn key_values() {
+        let span = Span { text: "58:5,3", line: 4, column: 1 };
+        assert_eq!(key_value(span, Span::number::<usize>, super::numbers::<usize>), Ok((58, vec![5, 3])));
+        let error = key_value(Span::new("58;5"), Span::number::<usize>, Span::number::<usize>).unwrap_err();
+        assert_eq!(error.message, "expected \":\" in \"58;5\"");
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(rule(Span::new("A > b,c"), Span::char, Span::char), Ok(('A', vec!['b', 'c'])));
+        let error = rule(Span::new("A > b,cd"), Span::char, Span::char).unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (7, "expected a single character, found \"cd\""));
+    }
+
+    #[test]
+    fn prefixes() {
+        let span = Span::new("Plant 4").strip_prefix("Plant ").unwrap();
+        assert_eq!((span.text, span.column), ("4", 7));
+        assert_eq!(Span::new("Plan 4").strip_prefix("Plant ").unwrap_err().message, "expected \"Plant ...\", found \"Plan 4\"");
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(range::<usize>(Span::new("3-7")), Ok((3, 7)));
+        assert_eq!(range::<usize>(Span::new("5")), Ok((5, 5)));
+        assert_eq!(range::<usize>(Span::new("3-x")).unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(turn::<isize>(Span::new("L3")), Ok((Turn::Left, 3)));
+        assert_eq!(turn::<isize>(Span::new("R12")), Ok((Turn::Right, 12)));
+        assert_eq!(turn::<isize>(Span::new("U1")).unwrap_err().column, 1);
+        assert_eq!(turn::<isize>(Span::new("R1x")).unwrap_err().column, 2);
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Position;
+
+// Same orientation as the grid: row grows downwards (south), col grows to the right (east)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Point {
+        Point { row, col }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn distance_squared(&self, other: &Point) -> usize {
+        let drow = self.row.abs_diff(other.row);
+        let dcol = self.col.abs_diff(other.col);
+        drow * drow + dcol * dcol
+    }
+
+    // Rotations by 90 degrees around the origin, as seen on screen
+    pub fn turn_left(&self) -> Point {
+        Point::new(-self.col, self.row)
+    }
+
+    pub fn turn_right(&self) -> Point {
+        Point::new(self.col, -self.row)
+    }
+
+    pub fn neighbours4(&self) -> [Point; 4] {
+        Direction::ALL.map(|direction| *self + direction.delta())
+    }
+
+    pub fn to_position(self) -> Option<Position> {
+        (self.row >= 0 && self.col >= 0).then_some((self.row as usize, self.col as usize))
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Point {
+        Point::new(row, col)
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Point {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    // Clockwise, starting from north
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn delta(&self) -> Point {
+        match self {
+            Direction::North => Point::new(-1, 0),
+            Direction::East => Point::new(0, 1),
+            Direction::South => Point::new(1, 0),
+            Direction::West => Point::new(0, -1),
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_on_screen() {
+        // Row grows downwards, so north is a negative row
+        assert_eq!(Direction::North.delta(), Point::new(-1, 0));
+        assert_eq!(Direction::East.delta(), Point::new(0, 1));
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::South.opposite(), Direction::North);
+    }
+
+    #[test]
+    fn points_turn_like_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.delta().turn_left(), direction.turn_left().delta());
+            assert_eq!(direction.delta().turn_right(), direction.turn_right().delta());
+        }
+        let point = Point::new(2, 3);
+        assert_eq!(point.turn_left(), Point::new(-3, 2));
+        assert_eq!(point.turn_right(), Point::new(3, -2));
+        assert_eq!(point.turn_left().turn_left(), -point);
+        assert_eq!(point.turn_right().turn_left(), point);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(-2, 3);
+        let b = Point::new(1, -1);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(b.manhattan(&a), 7);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!(a.manhattan(&a), 0);
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(Point::new(2, 0).to_position(), Some((2, 0)));
+        assert_eq!(Point::new(-1, 0).to_position(), None);
+        assert_eq!(Point::new(0, -1).to_position(), None);
+        assert_eq!(Point::from((3_usize, 4_usize)), Point::new(3, 4));
+        assert_eq!(Point::new(1, 1) + Direction::South.delta() * 2, Point::new(3, 1));
+    }
+}
@@ -0,0 +1,190 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::process;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::input::try_read_input;
+use crate::parse::ParseError;
+use crate::root::{resolve, set_root};
+
+pub const PARTS: [u8; 3] = [1, 2, 3];
+
+// Every event has 20 quests, one a day
+pub const DAYS: RangeInclusive<u8> = 1..=20;
+
+pub const CURRENT_EVENT: u16 = 2025;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+// Why a part has no answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuestError {
+    // The input could not be parsed
+    InvalidInput(ParseError),
+    // The input was parsed, but the part cannot be solved for it
+    Unsolvable(String),
+}
+
+impl fmt::Display for QuestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestError::InvalidInput(e) => write!(f, "invalid input: {}", e),
+            QuestError::Unsolvable(message) => write!(f, "no answer: {}", message),
+        }
+    }
+}
+
+impl Error for QuestError {}
+
+impl From<ParseError> for QuestError {
+    fn from(e: ParseError) -> QuestError {
+        QuestError::InvalidInput(e)
+    }
+}
+
+// Type erased version of a Solver, so that the day binaries and the runner can solve any part of
+// any day for an arbitrary input
+pub trait Quest: Sync {
+    fn event(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part_1(&self, input: &str) -> Result<String, QuestError>;
+    fn part_2(&self, input: &str) -> Result<String, QuestError>;
+    fn part_3(&self, input: &str) -> Result<String, QuestError>;
+
+    // Solves a part while timing the parsing of the input and the solving itself separately
+    fn timed(&self, part: u8, input: &str) -> Result<(String, Timing), QuestError>;
+
+    fn solve(&self, part: u8, input: &str) -> Result<String, QuestError> {
+        match part {
+            1 => self.part_1(input),
+            2 => self.part_2(input),
+            3 => self.part_3(input),
+            _ => panic!("Quests only have parts 1 to 3, not {}", part),
+        }
+    }
+}
+
+pub fn input_path(event: u16, day: u8, part: u8) -> PathBuf {
+    // The quests of the current event live in the root of the workspace, those of other events in
+    // an eventYYYY folder with its own inputs, so days of different events do not collide
+    if event == CURRENT_EVENT {
+        PathBuf::from(format!("inputs/day{:02}pt{}.txt", day, part))
+    }
+    else {
+        PathBuf::from(format!("event{}/inputs/quest{:02}pt{}.txt", event, day, part))
+    }
+}
+
+pub fn example_path(event: u16, day: u8, part: u8) -> PathBuf {
+    input_path(event, day, part).with_extension("example.txt")
+}
+
+// The key of a quest in the answers and baseline files, named like its inputs, e.g. day05 for the
+// current event and event2024-quest05 for another event
+pub fn quest_key(event: u16, day: u8) -> String {
+    if event == CURRENT_EVENT {
+        format!("day{:02}", day)
+    }
+    else {
+        format!("event{}-quest{:02}", event, day)
+    }
+}
+
+pub fn parse_quest_key(key: &str) -> Option<(u16, u8)> {
+    let (event, day) = match key.strip_prefix("event") {
+        Some(rest) => {
+            let (event, day) = rest.split_once("-quest")?;
+            (event.parse().ok()?, day)
+        }
+        None => (CURRENT_EVENT, key.strip_prefix("day")?),
+    };
+    Some((event, day.parse().ok()?))
+}
+
+pub fn run_quest(quest: &dyn Quest) {
+    // The only argument of the day binaries is the root of the workspace
+    let args: Vec<String> = env::args().skip(1).collect();
+    match root_arg(&args) {
+        Some(Some(root)) => {
+            set_root(root);
+        }
+        Some(None) => {}
+        None => {
+            eprintln!("Usage: day{:02} [--root PATH]", quest.day());
+            process::exit(2);
+        }
+    }
+
+    // A failing part is reported, but does not stop the remaining parts from running
+    for part in PARTS {
+        match try_read_input(resolve(input_path(quest.event(), quest.day(), part))) {
+            Ok(input) => match quest.solve(part, &input) {
+                Ok(answer) => println!("Part {}: {}", part, answer),
+                Err(e) => eprintln!("Part {}: {}", part, e),
+            },
+            Err(e) => eprintln!("Part {}: {}", part, e),
+        }
+    }
+}
+
+// The root given to a day binary, or None if the arguments are not understood
+fn root_arg(args: &[String]) -> Option<Option<&str>> {
+    match args {
+        [] => Some(None),
+        [flag, root] if flag == "--root" => Some(Some(root)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn root_is_the_only_argument() {
+        assert_eq!(root_arg(&args(&[])), Some(None));
+        assert_eq!(root_arg(&args(&["--root", "codes"])), Some(Some("codes")));
+        assert_eq!(root_arg(&args(&["--root"])), None);
+        assert_eq!(root_arg(&args(&["--rot", "codes"])), None);
+        assert_eq!(root_arg(&args(&["--root", "codes", "--part", "1"])), None);
+        assert_eq!(root_arg(&args(&["codes"])), None);
+    }
+
+    #[test]
+    fn describes_errors() {
+        let invalid = QuestError::from(ParseError { line: 2, column: 3, message: String::from("expected a number") });
+        assert_eq!(invalid.to_string(), "invalid input: line 2, column 3: expected a number");
+        let unsolvable = QuestError::Unsolvable(String::from("the result overflows"));
+        assert_eq!(unsolvable.to_string(), "no answer: the result overflows");
+    }
+
+    #[test]
+    fn paths_per_event() {
+        assert_eq!(input_path(CURRENT_EVENT, 3, 2), PathBuf::from("inputs/day03pt2.txt"));
+        assert_eq!(example_path(CURRENT_EVENT, 3, 2), PathBuf::from("inputs/day03pt2.example.txt"));
+        assert_eq!(input_path(2024, 12, 1), PathBuf::from("event2024/inputs/quest12pt1.txt"));
+    }
+
+    #[test]
+    fn keys_per_event() {
+        assert_eq!(quest_key(CURRENT_EVENT, 5), "day05");
+        assert_eq!(quest_key(2024, 12), "event2024-quest12");
+        assert_eq!(parse_quest_key("day05"), Some((CURRENT_EVENT, 5)));
+        assert_eq!(parse_quest_key("day5"), Some((CURRENT_EVENT, 5)));
+        assert_eq!(parse_quest_key("event2024-quest12"), Some((2024, 12)));
+        for key in ["five", "day", "day256", "event2024", "event-quest12", "event2024-day12"] {
+            assert_eq!(parse_quest_key(key), None, "{}", key);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// Environment variable pointing to the root of the workspace, which holds the inputs
+pub const ROOT_VAR: &str = "EVERYBODY_CODES_ROOT";
+
+static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+// Overrides the root for the rest of the program, e.g. from a command line flag. Returns false if
+// the root had already been set.
+pub fn set_root(root: impl Into<PathBuf>) -> bool {
+    ROOT.set(root.into()).is_ok()
+}
+
+// The root is taken from set_root, the environment variable or the workspace the crate is part
+// of, in that order, and falls back to the current directory
+pub fn root() -> PathBuf {
+    // Cargo sets the manifest dir when running a binary or test, otherwise the one of the build
+    // is tried, which only exists on the machine that built it
+    let manifest_dirs = [env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from), Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")))];
+    choose_root(ROOT.get(), env::var_os(ROOT_VAR), manifest_dirs.into_iter().flatten())
+}
+
+fn choose_root(set: Option<&PathBuf>, var: Option<OsString>, manifest_dirs: impl IntoIterator<Item = PathBuf>) -> PathBuf {
+    if let Some(root) = set {
+        return root.clone();
+    }
+    if let Some(root) = var {
+        return PathBuf::from(root);
+    }
+    manifest_dirs.into_iter()
+        .find_map(|dir| find_workspace_root(&dir))
+        .unwrap_or_default()
+}
+
+pub fn resolve(path: impl AsRef<Path>) -> PathBuf {
+    root().join(path)
+}
+
+fn find_workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A workspace with a member crate, inside a directory that is no workspace itself
+    fn workspace() -> (tempfile::TempDir, PathBuf, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("codes");
+        let member = root.join("day01");
+        fs::create_dir_all(member.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"day01\"]\n").unwrap();
+        fs::write(member.join("Cargo.toml"), "[package]\nname = \"day01\"\n").unwrap();
+        (dir, root, member)
+    }
+
+    #[test]
+    fn finds_the_workspace_above_a_member() {
+        let (dir, root, member) = workspace();
+        assert_eq!(find_workspace_root(&member.join("src")), Some(root.clone()));
+        assert_eq!(find_workspace_root(&member), Some(root.clone()));
+        assert_eq!(find_workspace_root(&root), Some(root));
+        assert_eq!(find_workspace_root(dir.path()), None);
+    }
+
+    #[test]
+    fn set_root_comes_first() {
+        let (_dir, _root, member) = workspace();
+        let set = PathBuf::from("/set");
+        assert_eq!(choose_root(Some(&set), Some(OsString::from("/var")), [member]), set);
+    }
+
+    #[test]
+    fn environment_variable_comes_before_the_workspace() {
+        let (_dir, _root, member) = workspace();
+        assert_eq!(choose_root(None, Some(OsString::from("/var")), [member]), PathBuf::from("/var"));
+    }
+
+    #[test]
+    fn first_manifest_dir_in_a_workspace() {
+        let (dir, root, member) = workspace();
+        assert_eq!(choose_root(None, None, [dir.path().to_path_buf(), member]), root);
+        // Falls back to the current directory
+        assert_eq!(choose_root(None, None, [dir.path().to_path_buf()]), PathBuf::new());
+        assert_eq!(choose_root(None, None, []), PathBuf::new());
+    }
+}
@@ -0,0 +1,267 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    pub distances: HashMap<N, usize>,
+    pub predecessors: HashMap<N, N>,
+    pub goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone> SearchResult<N> {
+    fn new() -> SearchResult<N> {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn visited(&self) -> impl Iterator<Item = &N> {
+        self.distances.keys()
+    }
+
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(&path[path.len() - 1]) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+// All searches accept several start nodes and stop at the first node for which is_goal holds.
+// Pass `|_| false` as goal to explore everything that is reachable.
+
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !result.distances.contains_key(&start) {
+            result.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        if is_goal(&current) {
+            result.goal = Some(current);
+            break;
+        }
+
+        let distance = result.distances[&current];
+        for next in neighbours(&current) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), distance + 1);
+                result.predecessors.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    result
+}
+
+pub fn dfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // Distances are the depths in the search tree, which are not necessarily the shortest ones
+    let mut result = SearchResult::new();
+    let mut stack: Vec<(N, usize, Option<N>)> = starts.into_iter()
+        .map(|start| (start, 0, None))
+        .collect();
+    stack.reverse();
+
+    while let Some((current, depth, previous)) = stack.pop() {
+        if result.distances.contains_key(&current) {
+            continue;
+        }
+        result.distances.insert(current.clone(), depth);
+        if let Some(previous) = previous {
+            result.predecessors.insert(current.clone(), previous);
+        }
+
+        if is_goal(&current) {
+            result.goal = Some(current);
+            break;
+        }
+
+        for next in neighbours(&current) {
+            if !result.distances.contains_key(&next) {
+                stack.push((next, depth + 1, Some(current.clone())));
+            }
+        }
+    }
+    result
+}
+
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone + Ord,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone + Ord,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    // The heuristic must never overestimate the remaining cost, otherwise the result is not
+    // guaranteed to be the shortest path. Dijkstra is the special case of a zero heuristic.
+    let mut result = SearchResult::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if !result.distances.contains_key(&start) {
+            result.distances.insert(start.clone(), 0);
+            heap.push(Reverse((heuristic(&start), 0, start)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, current))) = heap.pop() {
+        // Skip outdated entries, a shorter route to this node was found after it was pushed
+        if cost > result.distances[&current] {
+            continue;
+        }
+
+        if is_goal(&current) {
+            result.goal = Some(current);
+            break;
+        }
+
+        for (next, weight) in neighbours(&current) {
+            let new_cost = cost + weight;
+            if result.distances.get(&next).is_none_or(|&old| new_cost < old) {
+                result.distances.insert(next.clone(), new_cost);
+                result.predecessors.insert(next.clone(), current.clone());
+                heap.push(Reverse((new_cost + heuristic(&next), new_cost, next)));
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The direct edge from a to b is the longest route
+    fn weighted(node: &char) -> Vec<(char, usize)> {
+        match node {
+            'a' => vec![('b', 10), ('c', 1)],
+            'c' => vec![('d', 1)],
+            'd' => vec![('b', 1)],
+            'b' => vec![('e', 2)],
+            _ => vec![],
+        }
+    }
+
+    // A 7x7 room with a wall in column 3 that is open at the bottom row only
+    fn room(&(row, col): &(isize, isize)) -> Vec<((isize, isize), usize)> {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)].into_iter()
+            .map(|(dr, dc)| (row + dr, col + dc))
+            .filter(|&(r, c)| (0..7).contains(&r) && (0..7).contains(&c) && (c != 3 || r == 6))
+            .map(|next| (next, 1))
+            .collect()
+    }
+
+    #[test]
+    fn dijkstra_follows_the_weights() {
+        let unweighted = bfs(['a'], |node| weighted(node).into_iter().map(|(next, _)| next), |&node| node == 'b');
+        assert_eq!(unweighted.goal_distance(), Some(1));
+
+        let result = dijkstra(['a'], weighted, |&node| node == 'b');
+        assert_eq!(result.goal, Some('b'));
+        assert_eq!(result.goal_distance(), Some(3));
+        assert_eq!(result.goal_path(), Some(vec!['a', 'c', 'd', 'b']));
+    }
+
+    #[test]
+    fn astar_with_manhattan_heuristic() {
+        let goal = (0, 6);
+        let manhattan = |&(row, col): &(isize, isize)| ((row - goal.0).abs() + (col - goal.1).abs()) as usize;
+        let guided = astar([(0, 0)], room, manhattan, |&node| node == goal);
+        let blind = dijkstra([(0, 0)], room, |&node| node == goal);
+
+        // Down to the opening, through it and back up again
+        assert_eq!(guided.goal_distance(), Some(18));
+        assert_eq!(blind.goal_distance(), Some(18));
+        let path = guided.goal_path().unwrap();
+        assert_eq!(path.len(), 19);
+        assert_eq!((path[0], path[18]), ((0, 0), goal));
+        assert!(path.contains(&(6, 3)));
+        assert!(guided.visited().count() <= blind.visited().count());
+    }
+
+    #[test]
+    fn nearest_of_several_starts() {
+        let line = |&node: &i32| [(node - 1, 1), (node + 1, 1)].into_iter().filter(|&(next, _)| (0..=10).contains(&next));
+        let result = dijkstra([0, 10], line, |&node| node == 7);
+        assert_eq!(result.goal_distance(), Some(3));
+        assert_eq!(result.goal_path(), Some(vec![10, 9, 8, 7]));
+        assert_eq!(result.distance(&0), Some(0));
+    }
+
+    #[test]
+    fn explores_everything_without_a_goal() {
+        let result = dijkstra(['a'], weighted, |_| false);
+        assert_eq!(result.goal, None);
+        assert_eq!(result.goal_distance(), None);
+        assert_eq!(result.goal_path(), None);
+        assert_eq!(result.visited().count(), 5);
+        assert_eq!(result.distance(&'e'), Some(5));
+    }
+
+    #[test]
+    fn paths_to_visited_nodes_only() {
+        let result = dijkstra(['c'], weighted, |_| false);
+        assert_eq!(result.path_to(&'e'), Some(vec!['c', 'd', 'b', 'e']));
+        assert_eq!(result.path_to(&'c'), Some(vec!['c']));
+        // Not reachable from c
+        assert_eq!(result.path_to(&'a'), None);
+        assert_eq!(result.path_to(&'z'), None);
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use crate::parse::ParseError;
+use crate::quest::{CURRENT_EVENT, Quest, QuestError, Timing};
+
+// Typed interface of a quest: the input of a part is parsed once, after which each part returns
+// its answer instead of printing it. A malformed input is returned as an error instead of
+// panicking halfway through a part, and a part that cannot be solved for a parsed input returns a
+// Result as its answer. Every Solver is also a Quest, so it can be run by the day binaries and the
+// runner.
+pub trait Solver: Sync {
+    const EVENT: u16 = CURRENT_EVENT;
+    const DAY: u8;
+    type Input;
+    type Answer: IntoAnswer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Answer;
+    fn part2(&self, input: &Self::Input) -> Self::Answer;
+    fn part3(&self, input: &Self::Input) -> Self::Answer;
+}
+
+// The answer of a part as text, or the reason why the part has no answer
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! display_answers {
+    ($($answer:ty),*) => {
+        $(
+            impl IntoAnswer for $answer {
+                fn into_answer(self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answers!(usize, isize, u64, i64, u32, i32, String);
+
+impl<T: Display, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map(|answer| answer.to_string())
+            .map_err(|e| e.to_string())
+    }
+}
+
+fn solved<A: IntoAnswer>(answer: A) -> Result<String, QuestError> {
+    answer.into_answer().map_err(QuestError::Unsolvable)
+}
+
+impl<S: Solver> Quest for S {
+    fn event(&self) -> u16 {
+        S::EVENT
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn part_1(&self, input: &str) -> Result<String, QuestError> {
+        solved(self.part1(&self.parse(input)?))
+    }
+
+    fn part_2(&self, input: &str) -> Result<String, QuestError> {
+        solved(self.part2(&self.parse(input)?))
+    }
+
+    fn part_3(&self, input: &str) -> Result<String, QuestError> {
+        solved(self.part3(&self.parse(input)?))
+    }
+
+    fn timed(&self, part: u8, input: &str) -> Result<(String, Timing), QuestError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            1 => self.part1(&parsed),
+            2 => self.part2(&parsed),
+            3 => self.part3(&parsed),
+            _ => panic!("Quests only have parts 1 to 3, not {}", part),
+        };
+        let solve = start.elapsed();
+
+        Ok((solved(answer)?, Timing { parse, solve }))
+    }
+}